@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rrdbc::ast::signal::{ByteOrder, Signal, ValueType};
+use rrdbc::ast::signal_extended_value_type::SignalExtendedValueType;
+
+fn signal(start_bit: u32, size: u32) -> Signal {
+    Signal {
+        name: "Bench".into(),
+        multiplexer: None,
+        start_bit,
+        size,
+        byte_order: ByteOrder::LittleEndian,
+        value_type: ValueType::Unsigned,
+        factor: 0.1,
+        offset: -40.0,
+        min: None,
+        max: None,
+        unit: None,
+        receivers: None,
+    }
+}
+
+/// Byte-aligned signals take the fast path, the same signals shifted by one bit the
+/// general bit-by-bit path.
+fn bench_decode(c: &mut Criterion) {
+    let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, 0x0F];
+    for size in [8, 16, 32, 64] {
+        let aligned = signal(8, size);
+        let unaligned = signal(9, size);
+        c.bench_function(&format!("decode aligned {size} bits"), |b| {
+            b.iter(|| aligned.decode(black_box(&data), SignalExtendedValueType::Integer));
+        });
+        c.bench_function(&format!("decode unaligned {size} bits"), |b| {
+            b.iter(|| unaligned.decode(black_box(&data), SignalExtendedValueType::Integer));
+        });
+    }
+}
+
+criterion_group!(benches, bench_decode);
+criterion_main!(benches);
@@ -1,12 +1,28 @@
-use nom::bytes::complete::tag;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while1};
 use nom::sequence::delimited;
 use nom::{IResult, Parser};
 
 use super::common_parsers::dbc_identifier;
 use super::error::DbcParseError;
 
+/// A quoted attribute name. Some tools use names that are not identifiers, e.g. with
+/// spaces, so anything but a quote or a control character is accepted between the
+/// quotes. Names are always written back quoted.
 pub fn parser_attribute_name(input: &str) -> IResult<&str, &str, DbcParseError> {
-    delimited(tag("\""), dbc_identifier, tag("\"")).parse(input)
+    delimited(
+        tag("\""),
+        take_while1(|c: char| c != '"' && !c.is_control()),
+        tag("\""),
+    )
+    .parse(input)
+}
+
+/// The attribute name of an attribute value (`BA_`), quoted or not. Hand-edited files
+/// sometimes drop the quotes here; `BA_DEF_` and `BA_DEF_DEF_` still require them.
+/// Attribute values are always written back with a quoted name.
+pub fn parser_attribute_value_name(input: &str) -> IResult<&str, &str, DbcParseError> {
+    alt((parser_attribute_name, dbc_identifier)).parse(input)
 }
 
 #[cfg(test)]
@@ -25,4 +41,22 @@ mod tests {
             Ok(("", "RWEnvVar_wData_Val"))
         );
     }
+
+    #[test]
+    fn test_attribute_name_03() {
+        assert_eq!(
+            parser_attribute_name(r#""Send Type" STRING"#),
+            Ok((" STRING", "Send Type"))
+        );
+        assert!(parser_attribute_name(r#""""#).is_err());
+    }
+
+    #[test]
+    fn test_attribute_value_name_01() {
+        assert_eq!(
+            parser_attribute_value_name(r#""hello" 1"#),
+            Ok((" 1", "hello"))
+        );
+        assert_eq!(parser_attribute_value_name("hello 1"), Ok((" 1", "hello")));
+    }
 }
@@ -52,8 +52,8 @@ pub fn parser_attribute_value(input: &str) -> IResult<&str, AttributeValue, DbcP
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttributeDefinitionDefault {
-    attribute_name: String,
-    attribute_value: AttributeValue,
+    pub attribute_name: String,
+    pub attribute_value: AttributeValue,
 }
 
 impl fmt::Display for AttributeDefinitionDefault {
@@ -100,8 +100,8 @@ pub fn parser_attribute_definition_default(
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelationAttributeDefinitionDefault {
-    attribute_name: String,
-    attribute_value: AttributeValue,
+    pub attribute_name: String,
+    pub attribute_value: AttributeValue,
 }
 
 impl fmt::Display for RelationAttributeDefinitionDefault {
@@ -152,6 +152,29 @@ pub enum AttributeDefault {
     RelationAttribute(RelationAttributeDefinitionDefault),
 }
 
+impl AttributeDefault {
+    pub fn attribute_name(&self) -> &str {
+        match self {
+            AttributeDefault::Attribute(v) => &v.attribute_name,
+            AttributeDefault::RelationAttribute(v) => &v.attribute_name,
+        }
+    }
+
+    pub fn attribute_value(&self) -> &AttributeValue {
+        match self {
+            AttributeDefault::Attribute(v) => &v.attribute_value,
+            AttributeDefault::RelationAttribute(v) => &v.attribute_value,
+        }
+    }
+
+    pub(crate) fn attribute_value_mut(&mut self) -> &mut AttributeValue {
+        match self {
+            AttributeDefault::Attribute(v) => &mut v.attribute_value,
+            AttributeDefault::RelationAttribute(v) => &mut v.attribute_value,
+        }
+    }
+}
+
 impl fmt::Display for AttributeDefault {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -7,8 +7,9 @@ use nom::multi::separated_list0;
 use nom::{IResult, Parser};
 
 use super::attribute::parser_attribute_name;
+use super::attribute_default::AttributeValue;
 use super::char_string::{parser_char_string, CharString};
-use super::common_parsers::{multispacey, number_value, signed_integer, spacey};
+use super::common_parsers::{multispacey, number_value, spacey};
 use super::error::DbcParseError;
 
 #[derive(PartialEq, Debug, Clone)]
@@ -24,14 +25,26 @@ impl fmt::Display for AttributeIntegerValueType {
     }
 }
 
+/// Bound of an INT or HEX value type. Some tools write these in scientific notation
+/// (`INT 0 1e3`), which is accepted if it is a whole number in `i32` range. Anything else
+/// is an error, rather than parsing `1` and stopping at `e3`.
+fn parser_attribute_integer_bound(input: &str) -> IResult<&str, i32, DbcParseError> {
+    let (remain, value) = number_value(input)?;
+    if value.fract() != 0.0 || value < f64::from(i32::MIN) || value > f64::from(i32::MAX) {
+        return Err(nom::Err::Error(DbcParseError::BadInt));
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    Ok((remain, value as i32))
+}
+
 pub fn parser_attribute_integer_value_type(
     input: &str,
 ) -> IResult<&str, AttributeValueType, DbcParseError> {
     let res = map(
         (
             multispacey(tag("INT")),
-            multispacey(signed_integer),
-            multispacey(signed_integer),
+            multispacey(parser_attribute_integer_bound),
+            multispacey(parser_attribute_integer_bound),
         ),
         |(_, minimum, maximum)| AttributeIntegerValueType { minimum, maximum },
     )
@@ -68,8 +81,8 @@ pub fn parser_attribute_hex_value_type(
     let res = map(
         (
             multispacey(tag("HEX")),
-            multispacey(signed_integer),
-            multispacey(signed_integer),
+            multispacey(parser_attribute_integer_bound),
+            multispacey(parser_attribute_integer_bound),
         ),
         |(_, minimum, maximum)| AttributeHexValueType { minimum, maximum },
     )
@@ -96,6 +109,8 @@ pub struct AttributeFloatValueType {
 
 impl fmt::Display for AttributeFloatValueType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // like signal ranges, bounds are written in their shortest form that parses back
+        // to the same value: `0` stays `0` and `0.0` becomes `0`
         write!(f, "FLOAT {} {}", self.minimum, self.maximum)
     }
 }
@@ -221,6 +236,40 @@ impl fmt::Display for AttributeValueType {
     }
 }
 
+impl AttributeValueType {
+    /// Whether `value` has the kind of value this type holds: a number for INT, HEX,
+    /// FLOAT and ENUM (the index of the enum value), a string for STRING.
+    pub fn accepts(&self, value: &AttributeValue) -> bool {
+        matches!(
+            (self, value),
+            (
+                AttributeValueType::Integer(_)
+                    | AttributeValueType::Hex(_)
+                    | AttributeValueType::Float(_)
+                    | AttributeValueType::Enum(_),
+                AttributeValue::Double(_)
+            ) | (AttributeValueType::String(_), AttributeValue::String(_))
+        )
+    }
+
+    /// `value` as this type reads it: a quoted number such as `"5"` is the number for
+    /// INT, HEX and FLOAT, as tools treat it. Other values are returned unchanged.
+    pub fn resolve(&self, value: AttributeValue) -> AttributeValue {
+        match (self, value) {
+            (
+                AttributeValueType::Integer(_)
+                | AttributeValueType::Hex(_)
+                | AttributeValueType::Float(_),
+                AttributeValue::String(s),
+            ) => match s.0.trim().parse() {
+                Ok(number) => AttributeValue::Double(number),
+                Err(_) => AttributeValue::String(s),
+            },
+            (_, value) => value,
+        }
+    }
+}
+
 pub fn parser_attribute_value_type(
     input: &str,
 ) -> IResult<&str, AttributeValueType, DbcParseError> {
@@ -694,6 +743,62 @@ pub enum AttributeDefinition {
     NodeMappedRxSignal(NodeMappedRxSignalAttribute),
 }
 
+/// The kind of object an [`AttributeDefinition`] applies to, one per variant.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum AttributeObjectKind {
+    Network,
+    Node,
+    Message,
+    Signal,
+    EnvironmentVariable,
+    ControlUnitEnvironmentVariable,
+    NodeTxMessage,
+    NodeMappedRxSignal,
+}
+
+impl AttributeDefinition {
+    pub fn object_kind(&self) -> AttributeObjectKind {
+        match self {
+            AttributeDefinition::Network(_) => AttributeObjectKind::Network,
+            AttributeDefinition::Node(_) => AttributeObjectKind::Node,
+            AttributeDefinition::Message(_) => AttributeObjectKind::Message,
+            AttributeDefinition::Signal(_) => AttributeObjectKind::Signal,
+            AttributeDefinition::EnvironmentVariable(_) => AttributeObjectKind::EnvironmentVariable,
+            AttributeDefinition::ControlUnitEnvironmentVariable(_) => {
+                AttributeObjectKind::ControlUnitEnvironmentVariable
+            }
+            AttributeDefinition::NodeTxMessage(_) => AttributeObjectKind::NodeTxMessage,
+            AttributeDefinition::NodeMappedRxSignal(_) => AttributeObjectKind::NodeMappedRxSignal,
+        }
+    }
+
+    pub fn attribute_name(&self) -> &str {
+        match self {
+            AttributeDefinition::Network(v) => &v.attribute_name,
+            AttributeDefinition::Node(v) => &v.attribute_name,
+            AttributeDefinition::Message(v) => &v.attribute_name,
+            AttributeDefinition::Signal(v) => &v.attribute_name,
+            AttributeDefinition::EnvironmentVariable(v) => &v.attribute_name,
+            AttributeDefinition::ControlUnitEnvironmentVariable(v) => &v.attribute_name,
+            AttributeDefinition::NodeTxMessage(v) => &v.attribute_name,
+            AttributeDefinition::NodeMappedRxSignal(v) => &v.attribute_name,
+        }
+    }
+
+    pub fn attribute_value_type(&self) -> &AttributeValueType {
+        match self {
+            AttributeDefinition::Network(v) => &v.attribute_value_type,
+            AttributeDefinition::Node(v) => &v.attribute_value_type,
+            AttributeDefinition::Message(v) => &v.attribute_value_type,
+            AttributeDefinition::Signal(v) => &v.attribute_value_type,
+            AttributeDefinition::EnvironmentVariable(v) => &v.attribute_value_type,
+            AttributeDefinition::ControlUnitEnvironmentVariable(v) => &v.attribute_value_type,
+            AttributeDefinition::NodeTxMessage(v) => &v.attribute_value_type,
+            AttributeDefinition::NodeMappedRxSignal(v) => &v.attribute_value_type,
+        }
+    }
+}
+
 impl fmt::Display for AttributeDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -873,6 +978,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parser_attribute_integer_value_type_02() {
+        assert_eq!(
+            parser_attribute_integer_value_type("INT 0 1e3"),
+            Ok((
+                "",
+                AttributeValueType::Integer(AttributeIntegerValueType {
+                    minimum: 0,
+                    maximum: 1000
+                })
+            )),
+        );
+        assert_eq!(
+            parser_attribute_integer_value_type("INT 0 1.5"),
+            Err(nom::Err::Error(DbcParseError::BadAttributeIntegerValueType))
+        );
+        assert_eq!(
+            parser_attribute_integer_value_type("INT 0 1e10"),
+            Err(nom::Err::Error(DbcParseError::BadAttributeIntegerValueType))
+        );
+    }
+
     #[test]
     fn test_parser_attribute_hex_value_type_01() {
         assert_eq!(
@@ -1041,6 +1168,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parser_message_attribute_02() {
+        let input = r#"BA_DEF_ BO_ "Send Type" STRING;"#;
+        let (_, definition) = parser_message_attribute(input).unwrap();
+        assert_eq!(definition.attribute_name(), "Send Type");
+        assert_eq!(definition.to_string(), input);
+    }
+
     #[test]
     fn test_parser_signal_attribute_01() {
         assert_eq!(
@@ -1302,4 +1437,23 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_parser_attribute_definition_10() {
+        let input = r#"BA_DEF_ "X" FLOAT 0 50.5;"#;
+        let (remain, definition) = parser_attribute_definition(input).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(
+            definition.attribute_value_type(),
+            &AttributeValueType::Float(AttributeFloatValueType {
+                minimum: 0.0,
+                maximum: 50.5,
+            })
+        );
+        assert_eq!(definition.to_string(), input);
+
+        let (_, definition) =
+            parser_attribute_definition(r#"BA_DEF_ "X" FLOAT 0.0 50.50;"#).unwrap();
+        assert_eq!(definition.to_string(), input);
+    }
 }
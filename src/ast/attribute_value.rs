@@ -5,7 +5,7 @@ use nom::bytes::complete::tag;
 use nom::combinator::map;
 use nom::{IResult, Parser};
 
-use super::attribute::parser_attribute_name;
+use super::attribute::parser_attribute_value_name;
 use super::attribute_default::{parser_attribute_value, AttributeValue};
 use super::common_parsers::{
     multispacey, parser_env_var_name, parser_message_id, parser_node_name, parser_signal_name,
@@ -35,7 +35,7 @@ pub fn parser_network_attribute_value(
     let res = map(
         (
             multispacey(tag("BA_")),
-            multispacey(parser_attribute_name),
+            multispacey(parser_attribute_value_name),
             multispacey(parser_attribute_value),
             multispacey(tag(";")),
         ),
@@ -82,7 +82,7 @@ pub fn parser_node_attribute_value(
     let res = map(
         (
             multispacey(tag("BA_")),
-            multispacey(parser_attribute_name),
+            multispacey(parser_attribute_value_name),
             multispacey(tag("BU_")),
             multispacey(parser_node_name),
             multispacey(parser_attribute_value),
@@ -132,7 +132,7 @@ pub fn parser_message_attribute_value(
     let res = map(
         (
             multispacey(tag("BA_")),
-            multispacey(parser_attribute_name),
+            multispacey(parser_attribute_value_name),
             multispacey(tag("BO_")),
             multispacey(parser_message_id),
             multispacey(parser_attribute_value),
@@ -183,7 +183,7 @@ pub fn parser_signal_attribute_value(
     let res = map(
         (
             multispacey(tag("BA_")),
-            multispacey(parser_attribute_name),
+            multispacey(parser_attribute_value_name),
             multispacey(tag("SG_")),
             multispacey(parser_message_id),
             multispacey(parser_signal_name),
@@ -237,7 +237,7 @@ pub fn parser_environment_variable_attribute_value(
     let res = map(
         (
             multispacey(tag("BA_")),
-            multispacey(parser_attribute_name),
+            multispacey(parser_attribute_value_name),
             multispacey(tag("EV_")),
             multispacey(parser_env_var_name),
             multispacey(parser_attribute_value),
@@ -267,6 +267,211 @@ pub fn parser_environment_variable_attribute_value(
     }
 }
 
+/// Value of a relation attribute between a node and an environment variable.
+///
+/// example:
+///
+/// ```text
+/// BA_REL_ "ControlUnitEnvVarAttr" BU_EV_REL_ Node0 RWEnvVar_wData "Value";
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ControlUnitEnvironmentVariableAttributeValue {
+    pub attribute_name: String,
+    pub node_name: String,
+    pub env_var_name: String,
+    pub attribute_value: AttributeValue,
+}
+
+impl fmt::Display for ControlUnitEnvironmentVariableAttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            r#"BA_REL_ "{}" BU_EV_REL_ {} {} {};"#,
+            self.attribute_name, self.node_name, self.env_var_name, self.attribute_value
+        )
+    }
+}
+
+pub fn parser_control_unit_environment_variable_attribute_value(
+    input: &str,
+) -> IResult<&str, ObjectAttributeValue, DbcParseError> {
+    let res = map(
+        (
+            multispacey(tag("BA_REL_")),
+            multispacey(parser_attribute_value_name),
+            multispacey(tag("BU_EV_REL_")),
+            multispacey(parser_node_name),
+            multispacey(parser_env_var_name),
+            multispacey(parser_attribute_value),
+            multispacey(tag(";")),
+        ),
+        |(_, attribute_name, _, node_name, env_var_name, attribute_value, _)| {
+            ControlUnitEnvironmentVariableAttributeValue {
+                attribute_name: attribute_name.to_string(),
+                node_name: node_name.to_string(),
+                env_var_name: env_var_name.to_string(),
+                attribute_value,
+            }
+        },
+    )
+    .parse(input);
+
+    match res {
+        Ok((remain, value)) => {
+            log::info!("parse control unit environment variable attribute value: {value:?}");
+            Ok((
+                remain,
+                ObjectAttributeValue::ControlUnitEnvironmentVariable(value),
+            ))
+        }
+        Err(e) => {
+            log::trace!(
+                "parse control unit environment variable attribute value failed, e = {e:?}"
+            );
+            Err(nom::Err::Error(
+                DbcParseError::BadControlUnitEnvironmentVariableAttributeValue,
+            ))
+        }
+    }
+}
+
+/// Value of a relation attribute between a node and a message it sends.
+///
+/// example:
+///
+/// ```text
+/// BA_REL_ "NodeTxMessageAttr" BU_BO_REL_ Node0 1234 5;
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeTxMessageAttributeValue {
+    pub attribute_name: String,
+    pub node_name: String,
+    pub message_id: u32,
+    pub attribute_value: AttributeValue,
+}
+
+impl fmt::Display for NodeTxMessageAttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            r#"BA_REL_ "{}" BU_BO_REL_ {} {} {};"#,
+            self.attribute_name, self.node_name, self.message_id, self.attribute_value
+        )
+    }
+}
+
+pub fn parser_node_tx_message_attribute_value(
+    input: &str,
+) -> IResult<&str, ObjectAttributeValue, DbcParseError> {
+    let res = map(
+        (
+            multispacey(tag("BA_REL_")),
+            multispacey(parser_attribute_value_name),
+            multispacey(tag("BU_BO_REL_")),
+            multispacey(parser_node_name),
+            multispacey(parser_message_id),
+            multispacey(parser_attribute_value),
+            multispacey(tag(";")),
+        ),
+        |(_, attribute_name, _, node_name, message_id, attribute_value, _)| {
+            NodeTxMessageAttributeValue {
+                attribute_name: attribute_name.to_string(),
+                node_name: node_name.to_string(),
+                message_id,
+                attribute_value,
+            }
+        },
+    )
+    .parse(input);
+
+    match res {
+        Ok((remain, value)) => {
+            log::info!("parse node tx message attribute value: {value:?}");
+            Ok((remain, ObjectAttributeValue::NodeTxMessage(value)))
+        }
+        Err(e) => {
+            log::trace!("parse node tx message attribute value failed, e = {e:?}");
+            Err(nom::Err::Error(
+                DbcParseError::BadNodeTxMessageAttributeValue,
+            ))
+        }
+    }
+}
+
+/// Value of a relation attribute between a node and a signal it receives.
+///
+/// example:
+///
+/// ```text
+/// BA_REL_ "NodeRxSignalAttr" BU_SG_REL_ Node0 SG_ 1234 Signal0 5;
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeMappedRxSignalAttributeValue {
+    pub attribute_name: String,
+    pub node_name: String,
+    pub message_id: u32,
+    pub signal_name: String,
+    pub attribute_value: AttributeValue,
+}
+
+impl fmt::Display for NodeMappedRxSignalAttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            r#"BA_REL_ "{}" BU_SG_REL_ {} SG_ {} {} {};"#,
+            self.attribute_name,
+            self.node_name,
+            self.message_id,
+            self.signal_name,
+            self.attribute_value
+        )
+    }
+}
+
+pub fn parser_node_mapped_rx_signal_attribute_value(
+    input: &str,
+) -> IResult<&str, ObjectAttributeValue, DbcParseError> {
+    let res = map(
+        (
+            multispacey(tag("BA_REL_")),
+            multispacey(parser_attribute_value_name),
+            multispacey(tag("BU_SG_REL_")),
+            multispacey(parser_node_name),
+            multispacey(tag("SG_")),
+            multispacey(parser_message_id),
+            multispacey(parser_signal_name),
+            multispacey(parser_attribute_value),
+            multispacey(tag(";")),
+        ),
+        |(_, attribute_name, _, node_name, _, message_id, signal_name, attribute_value, _)| {
+            NodeMappedRxSignalAttributeValue {
+                attribute_name: attribute_name.to_string(),
+                node_name: node_name.to_string(),
+                message_id,
+                signal_name: signal_name.to_string(),
+                attribute_value,
+            }
+        },
+    )
+    .parse(input);
+
+    match res {
+        Ok((remain, value)) => {
+            log::info!("parse node mapped rx signal attribute value: {value:?}");
+            Ok((remain, ObjectAttributeValue::NodeMappedRxSignal(value)))
+        }
+        Err(e) => {
+            log::trace!("parse node mapped rx signal attribute value failed, e = {e:?}");
+            Err(nom::Err::Error(
+                DbcParseError::BadNodeMappedRxSignalAttributeValue,
+            ))
+        }
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectAttributeValue {
@@ -275,6 +480,34 @@ pub enum ObjectAttributeValue {
     Message(MessageAttributeValue),
     Signal(SignalAttributeValue),
     EnvironmentVariable(EnvironmentVariableAttributeValue),
+    ControlUnitEnvironmentVariable(ControlUnitEnvironmentVariableAttributeValue),
+    NodeTxMessage(NodeTxMessageAttributeValue),
+    NodeMappedRxSignal(NodeMappedRxSignalAttributeValue),
+}
+
+impl ObjectAttributeValue {
+    /// Whether this is a relation attribute value (`BA_REL_`).
+    pub fn is_relation(&self) -> bool {
+        matches!(
+            self,
+            ObjectAttributeValue::ControlUnitEnvironmentVariable(_)
+                | ObjectAttributeValue::NodeTxMessage(_)
+                | ObjectAttributeValue::NodeMappedRxSignal(_)
+        )
+    }
+
+    pub fn attribute_name(&self) -> &str {
+        match self {
+            ObjectAttributeValue::Network(v) => &v.attribute_name,
+            ObjectAttributeValue::Node(v) => &v.attribute_name,
+            ObjectAttributeValue::Message(v) => &v.attribute_name,
+            ObjectAttributeValue::Signal(v) => &v.attribute_name,
+            ObjectAttributeValue::EnvironmentVariable(v) => &v.attribute_name,
+            ObjectAttributeValue::ControlUnitEnvironmentVariable(v) => &v.attribute_name,
+            ObjectAttributeValue::NodeTxMessage(v) => &v.attribute_name,
+            ObjectAttributeValue::NodeMappedRxSignal(v) => &v.attribute_name,
+        }
+    }
 }
 
 impl fmt::Display for ObjectAttributeValue {
@@ -285,6 +518,9 @@ impl fmt::Display for ObjectAttributeValue {
             ObjectAttributeValue::Message(v) => write!(f, "{v}"),
             ObjectAttributeValue::Signal(v) => write!(f, "{v}"),
             ObjectAttributeValue::EnvironmentVariable(v) => write!(f, "{v}"),
+            ObjectAttributeValue::ControlUnitEnvironmentVariable(v) => write!(f, "{v}"),
+            ObjectAttributeValue::NodeTxMessage(v) => write!(f, "{v}"),
+            ObjectAttributeValue::NodeMappedRxSignal(v) => write!(f, "{v}"),
         }
     }
 }
@@ -298,6 +534,9 @@ pub fn parser_object_attribute_value(
         parser_message_attribute_value,
         parser_signal_attribute_value,
         parser_environment_variable_attribute_value,
+        parser_control_unit_environment_variable_attribute_value,
+        parser_node_tx_message_attribute_value,
+        parser_node_mapped_rx_signal_attribute_value,
     ))
     .parse(input);
 
@@ -431,6 +670,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parser_message_attribute_value_02() {
+        let (remain, value) =
+            parser_message_attribute_value(r#"BA_ "GenMsgCycleTime" BO_ 0x1F4 100;"#).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(
+            value,
+            ObjectAttributeValue::Message(MessageAttributeValue {
+                attribute_name: "GenMsgCycleTime".to_string(),
+                message_id: 500,
+                attribute_value: AttributeValue::Double(100.0),
+            })
+        );
+        assert_eq!(value.to_string(), r#"BA_ "GenMsgCycleTime" BO_ 500 100;"#);
+    }
+
     #[test]
     fn test_parser_signal_attribute_value_01() {
         assert_eq!(
@@ -542,4 +797,80 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_parser_network_attribute_value_02() {
+        let (remain, value) = parser_object_attribute_value("BA_ BusType \"CAN\";").unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(
+            value,
+            ObjectAttributeValue::Network(NetworkAttributeValue {
+                attribute_name: "BusType".to_string(),
+                attribute_value: AttributeValue::String(CharString("CAN".into()))
+            })
+        );
+        assert_eq!(value.to_string(), r#"BA_ "BusType" "CAN";"#);
+    }
+
+    #[test]
+    fn test_parser_control_unit_environment_variable_attribute_value_01() {
+        let input = r#"BA_REL_ "ControlUnitEnvVarAttr" BU_EV_REL_ Node0 RWEnvVar_wData "Value";"#;
+        let (remain, value) = parser_object_attribute_value(input).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(
+            value,
+            ObjectAttributeValue::ControlUnitEnvironmentVariable(
+                ControlUnitEnvironmentVariableAttributeValue {
+                    attribute_name: "ControlUnitEnvVarAttr".to_string(),
+                    node_name: "Node0".to_string(),
+                    env_var_name: "RWEnvVar_wData".to_string(),
+                    attribute_value: AttributeValue::String(CharString("Value".to_string())),
+                }
+            )
+        );
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parser_node_tx_message_attribute_value_01() {
+        let input = r#"BA_REL_ "NodeTxMessageAttr" BU_BO_REL_ Node0 1234 5;"#;
+        let (remain, value) = parser_object_attribute_value(input).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(
+            value,
+            ObjectAttributeValue::NodeTxMessage(NodeTxMessageAttributeValue {
+                attribute_name: "NodeTxMessageAttr".to_string(),
+                node_name: "Node0".to_string(),
+                message_id: 1234,
+                attribute_value: AttributeValue::Double(5.0),
+            })
+        );
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parser_node_mapped_rx_signal_attribute_value_01() {
+        let input = r#"BA_REL_ "NodeRxSignalAttr" BU_SG_REL_ Node0 SG_ 1234 Signal0 5;"#;
+        let (remain, value) = parser_object_attribute_value(input).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(
+            value,
+            ObjectAttributeValue::NodeMappedRxSignal(NodeMappedRxSignalAttributeValue {
+                attribute_name: "NodeRxSignalAttr".to_string(),
+                node_name: "Node0".to_string(),
+                message_id: 1234,
+                signal_name: "Signal0".to_string(),
+                attribute_value: AttributeValue::Double(5.0),
+            })
+        );
+        assert_eq!(value.to_string(), input);
+        assert_eq!(
+            parser_node_mapped_rx_signal_attribute_value(
+                r#"BA_REL_ "NodeRxSignalAttr" BU_SG_REL_ Node0 1234 Signal0 5;"#
+            ),
+            Err(nom::Err::Error(
+                DbcParseError::BadNodeMappedRxSignalAttributeValue
+            ))
+        );
+    }
 }
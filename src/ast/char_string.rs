@@ -41,12 +41,14 @@ pub fn parser_char_string(input: &str) -> IResult<&str, CharString, DbcParseErro
     Ok((res.0, CharString(res.1)))
 }
 
+/// A single printable character: any character but a control character, so `~`, `}`
+/// and decoded non-ASCII text are accepted.
+///
+/// Unused by the parsers of this crate: quoted strings such as comments and units are
+/// read by [`string_literal`] from [`nonescaped_string`] and [`escape_code`], which
+/// accept any character but an unescaped `"` or `\`.
 pub fn printable_character(input: &str) -> IResult<&str, &str, DbcParseError> {
-    recognize(satisfy(|c| {
-        let c = c as u32;
-        (0x20..0x75).contains(&c)
-    }))
-    .parse(input)
+    recognize(satisfy(|c| !c.is_control())).parse(input)
 }
 
 pub fn nonescaped_string(input: &str) -> IResult<&str, String, DbcParseError> {
@@ -161,4 +163,13 @@ world""#
             Ok(("", "hello \\I world".to_string()))
         );
     }
+
+    #[test]
+    fn test_printable_character_01() {
+        for c in ["u", "~", "}", " ", "é", "温"] {
+            assert_eq!(printable_character(c), Ok(("", c)));
+        }
+        assert!(printable_character("\n").is_err());
+        assert!(printable_character("\u{7f}").is_err());
+    }
 }
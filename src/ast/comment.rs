@@ -522,4 +522,48 @@ Bit7 (128) Invalid Individual"
             )),
         );
     }
+
+    #[test]
+    fn test_parser_network_comment_03() {
+        assert_eq!(
+            parser_network_comment(r#"CM_ "a; b; c";"#),
+            Ok((
+                "",
+                Comment::Network(NetworkComment {
+                    comment: CharString("a; b; c".into())
+                })
+            )),
+        );
+    }
+
+    #[test]
+    fn test_parser_network_comment_04() {
+        assert_eq!(
+            parser_network_comment(r#"CM_ "{~approx} 温度";"#),
+            Ok((
+                "",
+                Comment::Network(NetworkComment {
+                    comment: CharString("{~approx} 温度".into())
+                })
+            )),
+        );
+    }
+
+    #[test]
+    fn test_parser_signal_comment_03() {
+        assert_eq!(
+            parser_comment(
+                r#"CM_ SG_ 100 Speed "ends with a semicolon;";
+CM_ BO_ 100 "next";"#
+            ),
+            Ok((
+                r#"CM_ BO_ 100 "next";"#,
+                Comment::Signal(SignalComment {
+                    message_id: 100,
+                    signal_name: "Speed".into(),
+                    comment: CharString("ends with a semicolon;".into())
+                })
+            )),
+        );
+    }
 }
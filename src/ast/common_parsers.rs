@@ -1,11 +1,11 @@
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_while1};
 use nom::character::complete::{
-    alphanumeric1, digit0, digit1, i32, multispace0, one_of, satisfy, space0, u32,
+    alphanumeric1, digit1, hex_digit1, i32, multispace0, one_of, satisfy, space0, space1, u32,
 };
-use nom::combinator::{map, not, opt, recognize};
+use nom::combinator::{map, not, opt, peek, recognize, verify};
 use nom::multi::many0;
-use nom::sequence::{delimited, pair};
+use nom::sequence::{delimited, pair, preceded};
 use nom::{AsChar, IResult, Parser};
 
 use super::error::DbcParseError;
@@ -32,6 +32,8 @@ where
     delimited(multispace0, f, multispace0)
 }
 
+/// A C identifier: a letter or `_`, then letters, digits and `_`. DBC names use
+/// [`dbc_identifier`], which also allows `-`.
 pub fn c_identifier(input: &str) -> IResult<&str, &str, DbcParseError> {
     recognize((
         alt((tag("_"), recognize(satisfy(AsChar::is_alpha)))),
@@ -45,7 +47,9 @@ pub fn digit1to9(input: &str) -> IResult<&str, char, DbcParseError> {
 }
 
 pub fn uint(input: &str) -> IResult<&str, &str, DbcParseError> {
-    alt((tag("0"), recognize(pair(digit1to9, digit0)))).parse(input)
+    // not `digit0`: at the end of input it returns a remainder that `recognize` can't
+    // measure, which truncates e.g. `100` to `1`
+    alt((tag("0"), recognize(pair(digit1to9, opt(digit1))))).parse(input)
 }
 
 pub fn integer_body(input: &str) -> IResult<&str, &str, DbcParseError> {
@@ -85,10 +89,23 @@ pub fn float_value(input: &str) -> IResult<&str, f64, DbcParseError> {
     }
 }
 
-pub fn number_value(input: &str) -> IResult<&str, f64, DbcParseError> {
+fn unspaced_number_value(input: &str) -> IResult<&str, f64, DbcParseError> {
     alt((map(float_value, |f| f), map(integer_value, |i| i as f64))).parse(input)
 }
 
+/// A float or integer. Hand-edited files sometimes put spaces between a minus sign and
+/// the digits (`- 0.5`), which is accepted too.
+pub fn number_value(input: &str) -> IResult<&str, f64, DbcParseError> {
+    alt((
+        unspaced_number_value,
+        map(
+            preceded((tag("-"), space1, peek(digit1)), unspaced_number_value),
+            |f| -f,
+        ),
+    ))
+    .parse(input)
+}
+
 pub fn unsigned_integer(input: &str) -> IResult<&str, u32, DbcParseError> {
     u32.parse(input)
 }
@@ -97,61 +114,58 @@ pub fn signed_integer(input: &str) -> IResult<&str, i32, DbcParseError> {
     i32.parse(input)
 }
 
+/// The DBC keywords, which cannot be used as names.
+const DBC_KEYWORDS: [&str; 37] = [
+    "VERSION",
+    "NS_",
+    "NS_DESC_",
+    "CM_",
+    "BA_DEF_",
+    "BA_",
+    "VAL_",
+    "CAT_DEF_",
+    "CAT_",
+    "FILTER",
+    "BA_DEF_DEF_",
+    "EV_DATA_",
+    "ENVVAR_DATA_",
+    "SGTYPE_",
+    "SGTYPE_VAL_",
+    "BA_DEF_SGTYPE_",
+    "BA_SGTYPE_",
+    "SIG_TYPE_REF_",
+    "VAL_TABLE_",
+    "SIG_GROUP_",
+    "SIG_VALTYPE_",
+    "SIGTYPE_VALTYPE_",
+    "BO_TX_BU_",
+    "BA_DEF_REL_",
+    "BA_REL_",
+    "BA_DEF_DEF_REL_",
+    "BU_SG_REL_",
+    "BU_EV_REL_",
+    "BU_BO_REL_",
+    "SG_MUL_VAL_",
+    "BS_",
+    "BU_",
+    "BO_",
+    "SG_",
+    "EV_",
+    "VECTOR__INDEPENDENT_SIG_MSG",
+    "VECTOR__XXX",
+];
+
+/// A DBC keyword, as a whole word: `CM_` is one, but not the `CM_` of `CM_Status`.
 pub fn dbc_key_word(input: &str) -> IResult<&str, &str, DbcParseError> {
-    alt((
-        alt((
-            tag("VERSION"),
-            tag("NS_"),
-            tag("NS_DESC_"),
-            tag("CM_"),
-            tag("BA_DEF_"),
-            tag("BA_"),
-            tag("VAL_"),
-            tag("CAT_DEF_"),
-            tag("CAT_"),
-            tag("FILTER"),
-        )),
-        alt((
-            tag("BA_DEF_DEF_"),
-            tag("EV_DATA_"),
-            tag("ENVVAR_DATA_"),
-            tag("SGTYPE_"),
-            tag("SGTYPE_VAL_"),
-            tag("BA_DEF_SGTYPE_"),
-            tag("BA_SGTYPE_"),
-            tag("SIG_TYPE_REF_"),
-            tag("VAL_TABLE_"),
-            tag("SIG_GROUP_"),
-        )),
-        alt((
-            tag("SIG_VALTYPE_"),
-            tag("SIGTYPE_VALTYPE_"),
-            tag("BO_TX_BU_"),
-            tag("BA_DEF_REL_"),
-            tag("BA_REL_"),
-            tag("BA_DEF_DEF_REL_"),
-            tag("BU_SG_REL_"),
-            tag("BU_EV_REL_"),
-            tag("BU_BO_REL_"),
-            tag("SG_MUL_VAL_"),
-        )),
-        alt((
-            tag("BS_"),
-            tag("BU_"),
-            tag("BO_"),
-            tag("SG_"),
-            tag("EV_"),
-            tag("VECTOR__INDEPENDENT_SIG_MSG"),
-            tag("VECTOR__XXX"),
-        )),
-    ))
-    .parse(input)
+    verify(dbc_object_name, |word: &str| DBC_KEYWORDS.contains(&word)).parse(input)
 }
 
 pub fn dbc_object_name(input: &str) -> IResult<&str, &str, DbcParseError> {
     take_while1(|c: char| c.is_alphanumeric() || c == '_').parse(input)
 }
 
+/// A letter or `_`, then letters, digits, `_` and `-`. Vector tools restrict names to C
+/// identifiers, but files from other tools use `-` too, e.g. `Wheel-Speed`.
 pub fn dbc_identifier_01(input: &str) -> IResult<&str, &str, DbcParseError> {
     recognize((
         alt((tag("_"), recognize(satisfy(AsChar::is_alpha)))),
@@ -160,6 +174,12 @@ pub fn dbc_identifier_01(input: &str) -> IResult<&str, &str, DbcParseError> {
     .parse(input)
 }
 
+/// The name of a node, message, signal or environment variable: a [`dbc_identifier_01`]
+/// that is not a DBC keyword.
+///
+/// A name that is a keyword, e.g. a message named `FILTER`, is rejected with
+/// [`DbcParseError::UseKeywordAsIdentifier`], which message and signal parsers pass on
+/// instead of their generic error. Such names have to be renamed.
 pub fn dbc_identifier(input: &str) -> IResult<&str, &str, DbcParseError> {
     let res = not(dbc_key_word).parse(input);
     match res {
@@ -172,8 +192,17 @@ pub fn parser_node_name(input: &str) -> IResult<&str, &str, DbcParseError> {
     dbc_identifier(input)
 }
 
+/// A message ID in decimal, or in hex with a `0x` prefix as some tools write it.
 pub fn parser_message_id(input: &str) -> IResult<&str, u32, DbcParseError> {
-    unsigned_integer(input)
+    let hex: IResult<&str, &str, DbcParseError> =
+        preceded(tag_no_case("0x"), hex_digit1).parse(input);
+    let Ok((remain, digits)) = hex else {
+        return unsigned_integer(input);
+    };
+    match u32::from_str_radix(digits, 16) {
+        Ok(id) => Ok((remain, id)),
+        Err(_) => Err(nom::Err::Error(DbcParseError::BadInt)),
+    }
 }
 
 pub fn parser_signal_name(input: &str) -> IResult<&str, &str, DbcParseError> {
@@ -203,6 +232,32 @@ mod tests {
         assert_eq!(float_value("1.52588E-05"), Ok(("", 1.52588e-05)));
     }
 
+    #[test]
+    fn test_number_value_01() {
+        assert_eq!(number_value("100"), Ok(("", 100.0)));
+        assert_eq!(number_value("1e3"), Ok(("", 1000.0)));
+        assert_eq!(number_value("-25;"), Ok((";", -25.0)));
+    }
+
+    #[test]
+    fn test_number_value_02() {
+        assert_eq!(number_value("- 0.5,"), Ok((",", -0.5)));
+        assert_eq!(number_value("-  1)"), Ok((")", -1.0)));
+        assert!(number_value("- -1").is_err());
+        assert!(number_value("- x").is_err());
+    }
+
+    #[test]
+    fn test_parser_message_id_01() {
+        assert_eq!(parser_message_id("500 "), Ok((" ", 500)));
+        assert_eq!(parser_message_id("0x1F4 "), Ok((" ", 500)));
+        assert_eq!(parser_message_id("0X8000001f"), Ok(("", 0x8000_001F)));
+        assert_eq!(
+            parser_message_id("0x100000000"),
+            Err(nom::Err::Error(DbcParseError::BadInt))
+        );
+    }
+
     #[test]
     fn test_c_identifier_01() {
         assert_eq!(c_identifier("a"), Ok(("", "a")));
@@ -263,4 +318,23 @@ mod tests {
     fn test_dbc_identifier_04() {
         assert_eq!(dbc_identifier("_HelloWorld"), Ok(("", "_HelloWorld")));
     }
+
+    #[test]
+    fn test_dbc_identifier_05() {
+        for name in [
+            "FILTERED_Speed",
+            "CM_Status",
+            "VERSION_Major",
+            "BO_TX",
+            "SG_2",
+        ] {
+            assert_eq!(dbc_identifier(name), Ok(("", name)));
+        }
+        for keyword in ["BA_DEF_DEF_REL_", "VAL_TABLE_", "NS_DESC_", "SGTYPE_VAL_"] {
+            assert_eq!(
+                dbc_identifier(keyword),
+                Err(nom::Err::Error(DbcParseError::UseKeywordAsIdentifier))
+            );
+        }
+    }
 }
@@ -12,6 +12,7 @@ use super::common_parsers::{
     multispacey, number_value, parser_env_var_name, parser_node_name, spacey,
 };
 use super::error::DbcParseError;
+use super::nodes::VECTOR_XXX;
 
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -43,7 +44,7 @@ impl EnvVarType {
     }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum EnvVarAccessType {
     Unrestricted = 0x0000,
     Read = 0x0001,
@@ -102,11 +103,40 @@ pub struct EnvironmentVariable {
     pub access_nodes: Vec<String>,
 }
 
+impl EnvironmentVariable {
+    /// The read/write bits of `access_type`.
+    pub fn access(&self) -> EnvVarAccessType {
+        match self.access_type & 0x0003 {
+            0x0000 => EnvVarAccessType::Unrestricted,
+            0x0001 => EnvVarAccessType::Read,
+            0x0002 => EnvVarAccessType::Write,
+            _ => EnvVarAccessType::ReadWrite,
+        }
+    }
+
+    /// Whether the read bit (`0x0001`) of `access_type` is set.
+    pub fn is_readable(&self) -> bool {
+        self.access_type & 0x0001 != 0
+    }
+
+    /// Whether the write bit (`0x0002`) of `access_type` is set.
+    pub fn is_writable(&self) -> bool {
+        self.access_type & 0x0002 != 0
+    }
+
+    /// Whether the string bit (`0x8000`) of `access_type` is set, which makes the value
+    /// type a string regardless of `env_var_type`.
+    pub fn is_string_type(&self) -> bool {
+        self.access_type & 0x8000 != 0
+    }
+}
+
 impl fmt::Display for EnvironmentVariable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "EV_ {}: ", self.env_var_name)?;
         match self.env_var_type {
             EnvVarType::Float => write!(f, "1")?,
+            EnvVarType::String if !self.is_string_type() => write!(f, "2")?,
             EnvVarType::Integer | EnvVarType::String | EnvVarType::Data => write!(f, "0")?,
         }
         write!(f, " [{}|{}]", self.minimum, self.maximum)?;
@@ -114,14 +144,9 @@ impl fmt::Display for EnvironmentVariable {
         write!(f, "{} ", self.initial_value)?;
         write!(f, "{} ", self.ev_id)?;
         write!(f, "DUMMY_NODE_VECTOR")?;
-        if self.env_var_type == EnvVarType::String {
-            write!(f, "{:X}", self.access_type | 0x8000)?;
-        } else {
-            write!(f, "{:X}", self.access_type)?;
-        }
-        write!(f, " ")?;
+        write!(f, "{:X} ", self.access_type)?;
         if self.access_nodes.is_empty() {
-            write!(f, "Vector__XXX")?;
+            write!(f, "{VECTOR_XXX}")?;
         } else {
             write!(f, "{}", self.access_nodes.join(","))?;
         }
@@ -153,8 +178,14 @@ pub fn parser_env_id(input: &str) -> IResult<&str, u32, DbcParseError> {
     u32(input)
 }
 
-pub fn parser_access_type(input: &str) -> IResult<&str, &str, DbcParseError> {
-    hex_digit1(input)
+/// The hexadecimal access type after `DUMMY_NODE_VECTOR`. Values that do not fit in
+/// 16 bits are rejected.
+pub fn parser_access_type(input: &str) -> IResult<&str, u16, DbcParseError> {
+    let (remain, digits) = hex_digit1(input)?;
+    match u16::from_str_radix(digits, 16) {
+        Ok(access_type) => Ok((remain, access_type)),
+        Err(_) => Err(nom::Err::Error(DbcParseError::BadEnvironmentVariable)),
+    }
 }
 
 pub fn parser_env_var(input: &str) -> IResult<&str, EnvironmentVariable, DbcParseError> {
@@ -195,13 +226,11 @@ pub fn parser_env_var(input: &str) -> IResult<&str, EnvironmentVariable, DbcPars
             _,
             _,
         )| {
-            let mut env_var_type = if env_var_type == 0 {
-                EnvVarType::Integer
-            } else {
-                EnvVarType::Float
+            let mut env_var_type = match env_var_type {
+                0 => EnvVarType::Integer,
+                2 => EnvVarType::String,
+                _ => EnvVarType::Float,
             };
-            let access_type = u16::from_str_radix(access_type, 16).expect("invalid access type");
-
             if access_type & 0x8000 != 0 {
                 env_var_type = EnvVarType::String;
             }
@@ -299,6 +328,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parser_env_var_04() {
+        let env_var = EnvironmentVariable {
+            env_var_name: "X".to_string(),
+            env_var_type: EnvVarType::String,
+            minimum: 0.0,
+            maximum: 0.0,
+            unit: CharString(String::new()),
+            initial_value: 0.0,
+            ev_id: 4,
+            access_type: 3,
+            access_nodes: vec!["Node0".to_string()],
+        };
+        let input = r#"EV_ X: 2 [0|0] "" 0 4 DUMMY_NODE_VECTOR3 Node0;"#;
+        assert_eq!(parser_env_var(input), Ok(("", env_var.clone())));
+        assert_eq!(env_var.to_string(), input);
+    }
+
+    #[test]
+    fn test_parser_env_var_05() {
+        assert_eq!(
+            parser_env_var(r#"EV_ X: 0 [0|0] "" 0 1 DUMMY_NODE_VECTOR99999999 Node0;"#),
+            Err(nom::Err::Error(DbcParseError::BadEnvironmentVariable))
+        );
+    }
+
     #[test]
     fn test_environment_variable_string_01() {
         assert_eq!(
@@ -374,4 +429,33 @@ mod tests {
             r#"EV_ WriteOnlyEnvVar: 1 [0|1234] "" 60 3 DUMMY_NODE_VECTOR2 Node2,Node3;"#
         );
     }
+
+    #[test]
+    fn test_environment_variable_access_type_01() {
+        let env_var = |access_type| EnvironmentVariable {
+            env_var_name: "EnvVar".to_string(),
+            env_var_type: EnvVarType::Integer,
+            minimum: 0.0,
+            maximum: 0.0,
+            unit: CharString(String::new()),
+            initial_value: 0.0,
+            ev_id: 1,
+            access_type,
+            access_nodes: vec![],
+        };
+        let cases = [
+            (0x0000, EnvVarAccessType::Unrestricted, false, false, false),
+            (0x0001, EnvVarAccessType::Read, true, false, false),
+            (0x0002, EnvVarAccessType::Write, false, true, false),
+            (0x0003, EnvVarAccessType::ReadWrite, true, true, false),
+            (0x8001, EnvVarAccessType::Read, true, false, true),
+        ];
+        for (access_type, access, readable, writable, string) in cases {
+            let env_var = env_var(access_type);
+            assert_eq!(env_var.access(), access, "{access_type:#x}");
+            assert_eq!(env_var.is_readable(), readable, "{access_type:#x}");
+            assert_eq!(env_var.is_writable(), writable, "{access_type:#x}");
+            assert_eq!(env_var.is_string_type(), string, "{access_type:#x}");
+        }
+    }
 }
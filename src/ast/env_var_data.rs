@@ -22,6 +22,10 @@ use super::error::DbcParseError;
 /// ```text
 /// ENVVAR_DATA_ RWEnvVar_wData: 10;
 /// ```
+///
+/// The DBC format has no comment or attribute object type for environment variable
+/// data. Comments and attribute values for it are written against the environment
+/// variable itself (`CM_ EV_ RWEnvVar_wData "...";`, `BA_ "..." EV_ RWEnvVar_wData 1;`).
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnvironmentVariableData {
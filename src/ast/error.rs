@@ -16,6 +16,8 @@ pub enum DbcParseError {
     BadSignal,
     #[error("bad message header")]
     BadMessageHeader,
+    #[error("bad message transmitters")]
+    BadMessageTransmitters,
     #[error("bad comment")]
     BadComment,
     #[error("bad network comment")]
@@ -38,6 +40,25 @@ pub enum DbcParseError {
     BadSignalValueDescriptions,
     #[error("bad environment variable value descriptions")]
     BadEnvironmentVariableValueDescriptions,
+    #[error("bad signal extended value type")]
+    BadSignalExtendedValueType,
+    #[error("bad extended multiplexing")]
+    BadExtendedMultiplexing,
+    #[error("bad signal type value descriptions")]
+    BadSignalTypeValueDescriptions,
+    #[error("bad signal type attribute value")]
+    BadSignalTypeAttributeValue,
+    #[error("bad signal group")]
+    BadSignalGroup,
+
+    #[error("duplicate message id {0}")]
+    DuplicateMessageId(u32),
+    #[error("malformed signal: {0}")]
+    MalformedSignal(String),
+    #[error("malformed message: {0}")]
+    MalformedMessage(String),
+    #[error("unknown section {0}")]
+    UnknownSection(String),
 
     #[error("bad attribute integer value type")]
     BadAttributeIntegerValueType,
@@ -82,6 +103,12 @@ pub enum DbcParseError {
     BadSignalAttributeValue,
     #[error("bad environment variable attribute value")]
     BadEnvironmentVariableAttributeValue,
+    #[error("bad control unit environment variable attribute value")]
+    BadControlUnitEnvironmentVariableAttributeValue,
+    #[error("bad node tx message attribute value")]
+    BadNodeTxMessageAttributeValue,
+    #[error("bad node mapped rx signal attribute value")]
+    BadNodeMappedRxSignalAttributeValue,
 
     #[error("bad integer")]
     BadInt,
@@ -101,6 +128,16 @@ pub enum DbcParseError {
     Debug(ErrorKind),
 }
 
+/// A [`DbcParseError`] with the 1-based line and column where the failing section
+/// starts.
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[error("{line}:{column}: {error}")]
+pub struct PositionedError {
+    pub line: usize,
+    pub column: usize,
+    pub error: DbcParseError,
+}
+
 // error handling document:
 // - <https://github.com/rust-bakery/nom/blob/main/doc/error_management.md>
 impl ParseError<&str> for DbcParseError {
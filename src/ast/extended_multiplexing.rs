@@ -0,0 +1,162 @@
+use std::fmt;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::map;
+use nom::multi::{many0, separated_list1};
+use nom::sequence::separated_pair;
+use nom::{IResult, Parser};
+
+use super::common_parsers::{
+    multispacey, parser_message_id, parser_signal_name, spacey, unsigned_integer,
+};
+use super::error::DbcParseError;
+
+/// ```text
+/// multiplexer_value_range = unsigned_integer '-' unsigned_integer ;
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiplexerValueRange {
+    pub from: u32,
+    pub to: u32,
+}
+
+impl MultiplexerValueRange {
+    pub fn contains(&self, value: u64) -> bool {
+        u64::from(self.from) <= value && value <= u64::from(self.to)
+    }
+}
+
+impl fmt::Display for MultiplexerValueRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.from, self.to)
+    }
+}
+
+/// Extended multiplexing section, selects a multiplexed signal by ranges of values of
+/// its multiplexer switch. The switch can itself be multiplexed (`m3M`), which gives
+/// nested multiplexing.
+///
+/// ```text
+/// extended_multiplexing = 'SG_MUL_VAL_' message_id multiplexed_signal_name
+/// multiplexer_switch_name multiplexer_value_range {',' multiplexer_value_range} ';' ;
+/// ```
+///
+/// example:
+///
+/// ```text
+/// SG_MUL_VAL_ 100 Mux_2 Mux_1 3-3, 5-10;
+/// SG_MUL_VAL_ 100 Mux_3 Mux_2 3-3;
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtendedMultiplexing {
+    pub message_id: u32,
+    pub signal_name: String,
+    pub multiplexer_switch_name: String,
+    pub value_ranges: Vec<MultiplexerValueRange>,
+}
+
+impl fmt::Display for ExtendedMultiplexing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value_ranges = self
+            .value_ranges
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "SG_MUL_VAL_ {} {} {} {};",
+            self.message_id, self.signal_name, self.multiplexer_switch_name, value_ranges
+        )
+    }
+}
+
+fn parser_multiplexer_value_range(
+    input: &str,
+) -> IResult<&str, MultiplexerValueRange, DbcParseError> {
+    map(
+        separated_pair(unsigned_integer, tag("-"), unsigned_integer),
+        |(from, to)| MultiplexerValueRange { from, to },
+    )
+    .parse(input)
+}
+
+pub fn parser_extended_multiplexing(
+    input: &str,
+) -> IResult<&str, ExtendedMultiplexing, DbcParseError> {
+    let res = map(
+        (
+            multispacey(tag("SG_MUL_VAL_")),
+            spacey(parser_message_id),
+            spacey(parser_signal_name),
+            spacey(parser_signal_name),
+            separated_list1(tag(","), spacey(parser_multiplexer_value_range)),
+            spacey(tag(";")),
+            many0(line_ending),
+        ),
+        |(_, message_id, signal_name, multiplexer_switch_name, value_ranges, _, _)| {
+            ExtendedMultiplexing {
+                message_id,
+                signal_name: signal_name.to_string(),
+                multiplexer_switch_name: multiplexer_switch_name.to_string(),
+                value_ranges,
+            }
+        },
+    )
+    .parse(input);
+
+    match res {
+        Ok((remain, val)) => {
+            log::info!("parse extended multiplexing: {val:?}");
+            Ok((remain, val))
+        }
+        Err(e) => {
+            log::trace!("parse extended multiplexing failed, e = {e:?}");
+            Err(nom::Err::Error(DbcParseError::BadExtendedMultiplexing))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extended_multiplexing_string_01() {
+        assert_eq!(
+            ExtendedMultiplexing {
+                message_id: 100,
+                signal_name: "Mux_2".to_string(),
+                multiplexer_switch_name: "Mux_1".to_string(),
+                value_ranges: vec![
+                    MultiplexerValueRange { from: 3, to: 3 },
+                    MultiplexerValueRange { from: 5, to: 10 },
+                ],
+            }
+            .to_string(),
+            "SG_MUL_VAL_ 100 Mux_2 Mux_1 3-3, 5-10;"
+        );
+    }
+
+    #[test]
+    fn test_parser_extended_multiplexing_01() {
+        assert_eq!(
+            parser_extended_multiplexing("SG_MUL_VAL_ 100 Mux_2 Mux_1 3-3, 5-10;"),
+            Ok((
+                "",
+                ExtendedMultiplexing {
+                    message_id: 100,
+                    signal_name: "Mux_2".to_string(),
+                    multiplexer_switch_name: "Mux_1".to_string(),
+                    value_ranges: vec![
+                        MultiplexerValueRange { from: 3, to: 3 },
+                        MultiplexerValueRange { from: 5, to: 10 },
+                    ],
+                }
+            ))
+        );
+    }
+}
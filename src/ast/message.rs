@@ -1,8 +1,10 @@
+use std::collections::HashMap;
 use std::fmt;
+use std::fmt::Write as _;
 
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::line_ending;
+use nom::character::complete::{line_ending, multispace0, space1};
 use nom::combinator::map;
 use nom::multi::many0;
 use nom::{IResult, Parser};
@@ -11,7 +13,59 @@ use super::common_parsers::{
     dbc_identifier, multispacey, parser_message_id, parser_node_name, spacey, unsigned_integer,
 };
 use super::error::DbcParseError;
-use super::signal::{parser_signal, Signal};
+use super::nodes::{is_vector_xxx, VECTOR_XXX};
+use super::signal::{parser_signal, Signal, SignalValue};
+use super::signal_extended_value_type::{SignalExtendedValueType, SignalExtendedValueTypeList};
+use crate::error::SignalLayoutError;
+
+/// J1939 fields of a 29-bit extended CAN ID.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct J1939Id {
+    /// Priority, 0 (highest) to 7.
+    pub priority: u8,
+    /// Parameter group number. For PDU1 (PDU format below 240) the PDU specific byte is
+    /// a destination address and is not part of the PGN.
+    pub pgn: u32,
+    pub source_address: u8,
+}
+
+/// How a message is sent, from its `GenMsgSendType` and `GenMsgCycleTime` attributes.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SendType {
+    /// Sent periodically, e.g. `Cyclic` or `CyclicIfActive`.
+    Cyclic,
+    /// Sent when something happens, e.g. `Spontaneous`, `Event` or `OnChange`.
+    Event,
+    /// Sent periodically and on events, e.g. `CyclicAndSpontaneous`.
+    CyclicAndEvent,
+    /// No send type is given, or it is not recognized (e.g. `NotUsed`).
+    Unknown,
+}
+
+impl SendType {
+    /// Classify a `GenMsgSendType` value. Matching is case-insensitive and by keyword, so
+    /// vendor variants like `SpontaneousWithDelay` are recognized.
+    pub fn from_attribute(value: &str) -> SendType {
+        let value = value.to_ascii_lowercase();
+        let cyclic = value.contains("cyclic");
+        let event = ["spontaneous", "event", "onchange", "onwrite"]
+            .iter()
+            .any(|k| value.contains(k))
+            || (!cyclic && value.contains("ifactive"));
+        match (cyclic, event) {
+            (true, true) => SendType::CyclicAndEvent,
+            (true, false) => SendType::Cyclic,
+            (false, true) => SendType::Event,
+            (false, false) => SendType::Unknown,
+        }
+    }
+}
+
+/// Name of the pseudo message that holds the signals not assigned to any real message.
+pub const INDEPENDENT_SIG_MSG: &str = "VECTOR__INDEPENDENT_SIG_MSG";
+
+/// ID of the [`INDEPENDENT_SIG_MSG`] pseudo message.
+pub const INDEPENDENT_SIG_MSG_ID: u32 = 0xC000_0000;
 
 /// Message definition.
 /// Format: `BO_ <CAN-ID> <MessageName>: <MessageSize> <SendingNode>`
@@ -56,6 +110,232 @@ pub struct Message {
     pub signals: Vec<Signal>,
 }
 
+impl MessageHeader {
+    /// Whether the message has a real transmitter rather than `Vector__XXX`.
+    pub fn has_sender(&self) -> bool {
+        !is_vector_xxx(&self.transmitter)
+    }
+
+    /// Whether this is the [`INDEPENDENT_SIG_MSG`] pseudo message rather than a frame on
+    /// the bus.
+    pub fn is_independent_sig_msg(&self) -> bool {
+        self.id == INDEPENDENT_SIG_MSG_ID
+    }
+
+    /// Whether the ID is an extended (29-bit) CAN ID, marked by its most significant bit.
+    /// `id` keeps the flag, and `Display` writes it unchanged so files round-trip.
+    pub fn is_extended(&self) -> bool {
+        self.id & 0x8000_0000 != 0
+    }
+
+    /// The CAN ID without the extended flag: the 29-bit ID of extended messages, and `id`
+    /// unchanged for standard ones. A standard ID above `0x7FF` is not masked, so it
+    /// cannot alias a valid ID; [`NetworkAst::validate`](super::network_ast::NetworkAst::validate)
+    /// reports it.
+    pub fn can_id(&self) -> u32 {
+        if self.is_extended() {
+            self.id & 0x1FFF_FFFF
+        } else {
+            self.id
+        }
+    }
+
+    /// Whether the ID fits its format: standard IDs must not exceed `0x7FF`. Extended IDs
+    /// always fit, as [`MessageHeader::can_id`] keeps only their 29 bits.
+    pub fn has_valid_id(&self) -> bool {
+        self.is_extended() || self.id <= 0x7FF
+    }
+
+    /// Decompose the ID into J1939 fields, `None` for standard (11-bit) IDs.
+    pub fn j1939(&self) -> Option<J1939Id> {
+        if !self.is_extended() {
+            return None;
+        }
+        let id = self.can_id();
+        let pdu_format = (id >> 16) & 0xFF;
+        let pgn = if pdu_format < 240 {
+            (id >> 8) & 0x3_FF00
+        } else {
+            (id >> 8) & 0x3_FFFF
+        };
+        Some(J1939Id {
+            priority: ((id >> 26) & 0x7) as u8,
+            pgn,
+            source_address: (id & 0xFF) as u8,
+        })
+    }
+}
+
+impl Message {
+    /// Create a message without signals whose transmitter is `Vector__XXX`.
+    pub fn new_unsent(id: u32, name: impl Into<String>, size: u32) -> Self {
+        Message {
+            header: MessageHeader {
+                id,
+                name: name.into(),
+                size,
+                transmitter: VECTOR_XXX.to_string(),
+            },
+            signals: vec![],
+        }
+    }
+
+    /// Whether the message has a real transmitter rather than `Vector__XXX`.
+    pub fn has_sender(&self) -> bool {
+        self.header.has_sender()
+    }
+
+    /// Append `signal`, unless it [overlaps](Signal::overlaps) the bits of a signal the
+    /// message already has.
+    pub fn try_add_signal(&mut self, signal: Signal) -> Result<(), SignalLayoutError> {
+        if let Some(existing) = self.signals.iter().find(|s| s.overlaps(&signal)) {
+            return Err(SignalLayoutError::Overlap {
+                signal: signal.name,
+                existing: existing.name.clone(),
+            });
+        }
+        self.signals.push(signal);
+        Ok(())
+    }
+
+    /// Signals that look like a checksum: named like `CRC` or `Checksum` and 4, 8, 16 or
+    /// 32 bits wide. This is a naming heuristic, not a guarantee.
+    pub fn likely_checksum_signals(&self) -> Vec<&Signal> {
+        self.signals_named_like(&["crc", "checksum", "chksum", "chks"], |size| {
+            matches!(size, 4 | 8 | 16 | 32)
+        })
+    }
+
+    /// Signals that look like a rolling counter: named like `Counter`, `Alive` or `Cntr`
+    /// and 2 to 8 bits wide. This is a naming heuristic, not a guarantee.
+    pub fn likely_counter_signals(&self) -> Vec<&Signal> {
+        self.signals_named_like(&["counter", "alive", "cntr", "rolling"], |size| {
+            (2..=8).contains(&size)
+        })
+    }
+
+    fn signals_named_like(&self, patterns: &[&str], size_ok: impl Fn(u32) -> bool) -> Vec<&Signal> {
+        self.signals
+            .iter()
+            .filter(|signal| {
+                let name = signal.name.to_ascii_lowercase();
+                patterns.iter().any(|p| name.contains(p)) && size_ok(signal.size)
+            })
+            .collect()
+    }
+
+    /// Signals spread over more bytes than their size needs, e.g. an 8-bit signal
+    /// starting at bit 4. Byte-aligned signals can be decoded with plain byte loads.
+    pub fn straddling_signals(&self) -> Vec<&Signal> {
+        self.signals
+            .iter()
+            .filter(|signal| {
+                let mut bytes: Vec<u32> =
+                    signal.bit_positions().iter().map(|pos| pos / 8).collect();
+                bytes.sort_unstable();
+                bytes.dedup();
+                bytes.len() > signal.size.div_ceil(8) as usize
+            })
+            .collect()
+    }
+
+    /// Payload bits no signal uses, in ascending order, i.e. the free space for new
+    /// signals. A bit used by a multiplexed signal of any group counts as used.
+    pub fn unused_bits(&self) -> Vec<usize> {
+        let mut used = vec![false; self.header.size as usize * 8];
+        for signal in &self.signals {
+            for pos in signal.bit_positions() {
+                if let Some(bit) = used.get_mut(pos as usize) {
+                    *bit = true;
+                }
+            }
+        }
+        used.iter()
+            .enumerate()
+            .filter_map(|(pos, used)| (!used).then_some(pos))
+            .collect()
+    }
+
+    /// Decode the signals of the multiplex group `mux_value`, as if the multiplexer switch
+    /// held that value.
+    ///
+    /// The override takes precedence over the switch bits in `data`: signals with
+    /// `m<mux_value>` are decoded, other multiplexed signals are not, whatever the payload
+    /// says. Signals that are not multiplexed, including the switch itself, are always
+    /// decoded from `data`. `extended_value_types` are the network's `SIG_VALTYPE_`
+    /// entries, see
+    /// [`NetworkAst::signal_extended_value_types`](super::network_ast::NetworkAst::signal_extended_value_types);
+    /// signals without one are decoded as integers. `VAL_` is a network section, so values
+    /// are [`SignalValue::Physical`]; see
+    /// [`NetworkAst::decode_frame`](super::network_ast::NetworkAst::decode_frame) for the
+    /// auto-detecting decode with labels.
+    pub fn decode_frame_with_mux(
+        &self,
+        data: &[u8],
+        mux_value: u32,
+        extended_value_types: &[SignalExtendedValueTypeList],
+    ) -> HashMap<String, SignalValue> {
+        self.signals
+            .iter()
+            .filter(|signal| {
+                signal
+                    .multiplexer
+                    .as_ref()
+                    .and_then(|m| m.multiplexer_signal)
+                    .map_or(true, |value| value == mux_value)
+            })
+            .filter_map(|signal| {
+                let extended_value_type = extended_value_types
+                    .iter()
+                    .find(|v| v.message_id == self.header.id && v.signal_name == signal.name)
+                    .map_or(SignalExtendedValueType::Integer, |v| v.value_type);
+                let value = signal.decode(data, extended_value_type)?;
+                Some((signal.name.clone(), SignalValue::Physical(value)))
+            })
+            .collect()
+    }
+
+    /// Render the payload as an ASCII grid, one row per byte and bits 7 to 0 from left
+    /// to right, with each bit showing the key of the signal that occupies it.
+    ///
+    /// Signals are keyed `A`-`Z` then `a`-`z` (`?` beyond that) in definition order and
+    /// listed below the grid. Bits used by more than one signal are marked `#`, unused
+    /// bits `.`.
+    ///
+    /// ```text
+    ///      7 6 5 4 3 2 1 0
+    ///   0  A A A A A A A A
+    ///   1  . . . . B B B B
+    /// A: Rpm
+    /// B: Gear
+    /// ```
+    pub fn layout_ascii(&self) -> String {
+        let mut keys = ('A'..='Z').chain('a'..='z');
+        let mut cells = vec!['.'; self.header.size as usize * 8];
+        let mut legend = String::new();
+        for signal in &self.signals {
+            let key = keys.next().unwrap_or('?');
+            let _ = writeln!(legend, "{key}: {}", signal.name);
+            for pos in signal.bit_positions() {
+                if let Some(cell) = cells.get_mut(pos as usize) {
+                    *cell = if *cell == '.' { key } else { '#' };
+                }
+            }
+        }
+
+        let mut out = String::from("     7 6 5 4 3 2 1 0\n");
+        for (byte, bits) in cells.chunks(8).enumerate() {
+            let _ = write!(out, "{byte:>3} ");
+            for bit in bits.iter().rev() {
+                out.push(' ');
+                out.push(*bit);
+            }
+            out.push('\n');
+        }
+        out + &legend
+    }
+}
+
 impl fmt::Display for MessageHeader {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -76,8 +356,10 @@ impl fmt::Display for Message {
     }
 }
 
+/// A [`dbc_identifier`], or [`INDEPENDENT_SIG_MSG`] which is a keyword but names the
+/// pseudo message.
 fn parser_message_name(input: &str) -> IResult<&str, &str, DbcParseError> {
-    dbc_identifier(input)
+    alt((tag(INDEPENDENT_SIG_MSG), dbc_identifier)).parse(input)
 }
 
 fn parser_message_size(input: &str) -> IResult<&str, u32, DbcParseError> {
@@ -85,7 +367,7 @@ fn parser_message_size(input: &str) -> IResult<&str, u32, DbcParseError> {
 }
 
 fn parser_transmitter(input: &str) -> IResult<&str, &str, DbcParseError> {
-    alt((parser_node_name, tag("Vector__XXX"))).parse(input)
+    alt((parser_node_name, tag(VECTOR_XXX))).parse(input)
 }
 
 fn parser_message_header(input: &str) -> IResult<&str, MessageHeader, DbcParseError> {
@@ -114,13 +396,22 @@ fn parser_message_header(input: &str) -> IResult<&str, MessageHeader, DbcParseEr
         }
         Err(e) => {
             log::trace!("parse message header failed, e = {e:?}");
-            Err(nom::Err::Error(DbcParseError::BadMessageHeader))
+            match e {
+                // name the actual problem, a keyword used as the message name
+                nom::Err::Error(DbcParseError::UseKeywordAsIdentifier) => Err(e),
+                _ => Err(nom::Err::Error(DbcParseError::BadMessageHeader)),
+            }
         }
     }
 }
 
+/// A message and its signals.
+///
+/// A message or signal named after a keyword fails with
+/// [`DbcParseError::UseKeywordAsIdentifier`] as a [`nom::Err::Failure`], so that the
+/// error reaches the caller instead of ending the messages early.
 pub fn parser_dbc_message(input: &str) -> IResult<&str, Message, DbcParseError> {
-    map(
+    let (remain, message) = map(
         (
             parser_message_header,
             many0(parser_signal),
@@ -129,6 +420,51 @@ pub fn parser_dbc_message(input: &str) -> IResult<&str, Message, DbcParseError>
         |(header, signals, _)| Message { header, signals },
     )
     .parse(input)
+    .map_err(|e| match e {
+        nom::Err::Error(DbcParseError::UseKeywordAsIdentifier) => {
+            nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier)
+        }
+        e => e,
+    })?;
+    if let Some(line) = keyword_line(remain, "SG_") {
+        if let Err(nom::Err::Error(DbcParseError::UseKeywordAsIdentifier)) = parser_signal(line) {
+            return Err(nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier));
+        }
+    }
+    Ok((remain, message))
+}
+
+/// If `input` starts with `keyword` as a whole word, the rest of its first line.
+fn keyword_line<'a>(input: &'a str, keyword: &str) -> Option<&'a str> {
+    let res: IResult<&str, _, DbcParseError> = (multispace0, tag(keyword), space1).parse(input);
+    res.ok()
+        .map(|_| input.trim_start().lines().next().unwrap_or_default())
+}
+
+/// Like [`parser_dbc_message`], but a `SG_` line that is not a valid signal is an error
+/// instead of the end of the message.
+pub fn parser_dbc_message_strict(input: &str) -> IResult<&str, Message, DbcParseError> {
+    let (remain, message) = parser_dbc_message(input)?;
+    if let Some(line) = keyword_line(remain, "SG_") {
+        log::trace!("malformed signal in message {}: {line}", message.header.id);
+        return Err(nom::Err::Failure(DbcParseError::MalformedSignal(
+            line.to_string(),
+        )));
+    }
+    Ok((remain, message))
+}
+
+/// All messages, using [`parser_dbc_message_strict`]. A `BO_` line that is not a valid
+/// message is an error instead of the end of the messages.
+pub fn parser_dbc_messages_strict(input: &str) -> IResult<&str, Vec<Message>, DbcParseError> {
+    let (remain, messages) = many0(parser_dbc_message_strict).parse(input)?;
+    if let Some(line) = keyword_line(remain, "BO_") {
+        log::trace!("malformed message: {line}");
+        return Err(nom::Err::Failure(DbcParseError::MalformedMessage(
+            line.to_string(),
+        )));
+    }
+    Ok((remain, messages))
 }
 
 #[cfg(test)]
@@ -294,4 +630,359 @@ mod tests {
             )),
         );
     }
+
+    #[test]
+    fn test_dbc_message_header_11() {
+        let (_, message) = parser_dbc_message(
+            r#"BO_ 100 Wheel-Data: 8 ABS
+ SG_ Wheel-Speed : 0|16@1+ (0.01,0) [0|0] "km/h" Vector__XXX
+"#,
+        )
+        .unwrap();
+        assert_eq!(message.header.name, "Wheel-Data");
+        assert_eq!(message.signals[0].name, "Wheel-Speed");
+    }
+
+    #[test]
+    fn test_dbc_message_header_12() {
+        let input = "BO_ 2147487969 Extended: 8 ECU";
+        let (_, header) = parser_message_header(input).unwrap();
+        assert!(header.is_extended());
+        assert_eq!(header.can_id(), 0x10E1);
+        assert_eq!(header.id, 2_147_487_969);
+        assert_eq!(header.to_string(), input);
+    }
+
+    #[test]
+    fn test_dbc_message_header_14() {
+        let (_, header) =
+            parser_message_header("BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG: 0 Vector__XXX")
+                .unwrap();
+        assert_eq!(header.id, INDEPENDENT_SIG_MSG_ID);
+        assert!(header.is_extended());
+        assert_eq!(header.can_id(), 0);
+        assert!(header.has_valid_id());
+        let (_, header) = parser_message_header("BO_ 2047 Widest: 8 ECU").unwrap();
+        assert_eq!(header.can_id(), 0x7FF);
+        assert!(header.has_valid_id());
+        let (_, header) = parser_message_header("BO_ 2048 Wide: 8 ECU").unwrap();
+        assert_eq!(header.can_id(), 2048);
+        assert!(!header.has_valid_id());
+    }
+
+    #[test]
+    fn test_dbc_message_header_13() {
+        assert_eq!(
+            parser_message_header("BO_ 100 BS_: 8 ECU"),
+            Err(nom::Err::Error(DbcParseError::UseKeywordAsIdentifier))
+        );
+        assert_eq!(
+            parser_message_header("BO_ 100 Status 8 ECU"),
+            Err(nom::Err::Error(DbcParseError::BadMessageHeader))
+        );
+        assert_eq!(
+            parser_dbc_messages_strict("BO_ 100 BS_: 8 ECU\n"),
+            Err(nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier))
+        );
+        assert_eq!(
+            parser_dbc_messages_strict(
+                "BO_ 100 Status: 8 ECU\n SG_ CM_ : 0|8@1+ (1,0) [0|0] \"\" ECU\n"
+            ),
+            Err(nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier))
+        );
+        assert_eq!(
+            parser_dbc_message("BO_ 100 BS_: 8 ECU\n"),
+            Err(nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier))
+        );
+        assert_eq!(
+            parser_dbc_message("BO_ 100 Status: 8 ECU\n SG_ CM_ : 0|8@1+ (1,0) [0|0] \"\" ECU\n"),
+            Err(nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier))
+        );
+        let (_, message) = parser_dbc_message(
+            "BO_ 1 FILTERED_Speed: 8 ECU\n SG_ CM_Status : 0|8@1+ (1,0) [0|0] \"\" ECU\n",
+        )
+        .unwrap();
+        assert_eq!(message.header.name, "FILTERED_Speed");
+        assert_eq!(message.signals[0].name, "CM_Status");
+    }
+
+    #[test]
+    fn test_message_new_unsent_01() {
+        let message = Message::new_unsent(10, "Placeholder", 8);
+        assert!(!message.has_sender());
+        assert_eq!(message.to_string(), "BO_ 10 Placeholder: 8 Vector__XXX\n");
+    }
+
+    #[test]
+    fn test_message_has_sender_01() {
+        let (_, message) = parser_dbc_message("BO_ 835 BREMSE_33: 8 ABS").unwrap();
+        assert!(message.has_sender());
+    }
+
+    #[test]
+    fn test_message_layout_ascii_01() {
+        let (_, message) = parser_dbc_message(
+            r#"BO_ 100 Engine: 2 ECU
+ SG_ Rpm : 0|12@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Gear : 11|4@1+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            message.layout_ascii(),
+            "     7 6 5 4 3 2 1 0
+  0  A A A A A A A A
+  1  . B B B # A A A
+A: Rpm
+B: Gear
+"
+        );
+    }
+
+    #[test]
+    fn test_message_try_add_signal_01() {
+        let (_, signals) = many0(parser_signal)
+            .parse(
+                r#" SG_ Rpm : 0|12@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Gear : 11|4@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Temp : 12|4@1+ (1,0) [0|0] "" Vector__XXX
+"#,
+            )
+            .unwrap();
+        let mut message = Message::new_unsent(100, "Engine", 2);
+        let mut signals = signals.into_iter();
+        assert_eq!(message.try_add_signal(signals.next().unwrap()), Ok(()));
+        assert_eq!(
+            message.try_add_signal(signals.next().unwrap()),
+            Err(SignalLayoutError::Overlap {
+                signal: "Gear".to_string(),
+                existing: "Rpm".to_string(),
+            })
+        );
+        assert_eq!(message.try_add_signal(signals.next().unwrap()), Ok(()));
+        assert_eq!(message.signals.len(), 2);
+    }
+
+    #[test]
+    fn test_send_type_from_attribute_01() {
+        let cases = [
+            ("Cyclic", SendType::Cyclic),
+            ("CyclicIfActive", SendType::Cyclic),
+            ("Spontaneous", SendType::Event),
+            ("SpontaneousWithDelay", SendType::Event),
+            ("IfActive", SendType::Event),
+            ("event", SendType::Event),
+            ("CyclicAndSpontaneous", SendType::CyclicAndEvent),
+            ("NotUsed", SendType::Unknown),
+        ];
+        for (value, send_type) in cases {
+            assert_eq!(SendType::from_attribute(value), send_type, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_message_straddling_signals_01() {
+        let (_, message) = parser_dbc_message(
+            r#"BO_ 100 Engine: 8 ECU
+ SG_ Aligned : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Wide : 8|12@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Nibble : 20|4@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Shifted : 28|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Crossing : 38|4@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Motorola : 55|16@0+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let names: Vec<&str> = message
+            .straddling_signals()
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Shifted", "Crossing"]);
+    }
+
+    #[test]
+    fn test_dbc_message_independent_sig_msg_01() {
+        let (remain, message) = parser_dbc_message(
+            "BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG: 0 Vector__XXX\n SG_ Orphan : 0|8@1+ (1,0) [0|0] \"\" Vector__XXX\n",
+        )
+        .unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(message.header.id, INDEPENDENT_SIG_MSG_ID);
+        assert_eq!(message.header.name, INDEPENDENT_SIG_MSG);
+        assert!(message.header.is_independent_sig_msg());
+        assert!(!message.header.has_sender());
+        assert!(is_vector_xxx(&message.header.transmitter));
+        assert_eq!(message.signals[0].receivers, Some(vec![VECTOR_XXX.into()]));
+        assert_eq!(message.signals[0].receiver_count(), 0);
+        assert_eq!(
+            message.to_string(),
+            "BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG: 0 Vector__XXX\n\tSG_ Orphan : 0|8@1+ (1,0) [0|0] \"\" Vector__XXX\n"
+        );
+        assert!(!Message::new_unsent(1, "First", 8)
+            .header
+            .is_independent_sig_msg());
+    }
+
+    #[test]
+    fn test_message_unused_bits_01() {
+        let (_, message) = parser_dbc_message(
+            r#"BO_ 100 Muxed: 3 ECU
+ SG_ Mux M : 0|4@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Speed m0 : 8|4@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Temperature m1 : 10|4@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Counter : 23|2@0+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let mut expected: Vec<usize> = (4..8).collect();
+        expected.extend(14..22);
+        assert_eq!(message.unused_bits(), expected);
+
+        let (_, empty) = parser_dbc_message("BO_ 200 Empty: 1 ECU\n").unwrap();
+        assert_eq!(empty.unused_bits(), (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_message_decode_frame_with_mux_01() {
+        let (_, message) = parser_dbc_message(
+            r#"BO_ 100 Muxed: 8 ECU
+ SG_ Mux M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Speed m0 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Temperature m1 : 8|8@1+ (0.5,0) [0|0] "" Vector__XXX
+ SG_ Counter : 16|8@1+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let data = [0, 80, 7, 0, 0, 0, 0, 0];
+        assert_eq!(
+            message.decode_frame_with_mux(&data, 1, &[]),
+            HashMap::from([
+                ("Mux".to_string(), SignalValue::Physical(0.0)),
+                ("Temperature".to_string(), SignalValue::Physical(40.0)),
+                ("Counter".to_string(), SignalValue::Physical(7.0)),
+            ])
+        );
+        assert_eq!(
+            message.decode_frame_with_mux(&data, 0, &[]).get("Speed"),
+            Some(&SignalValue::Physical(80.0))
+        );
+        assert!(!message
+            .decode_frame_with_mux(&data, 2, &[])
+            .contains_key("Speed"));
+    }
+
+    #[test]
+    fn test_message_decode_frame_with_mux_02() {
+        let (_, message) = parser_dbc_message(
+            r#"BO_ 100 Muxed: 8 ECU
+ SG_ Mux M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Level m1 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Temperature m2 : 32|32@1- (0.5,10) [0|0] "degC" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let value_types = [SignalExtendedValueTypeList {
+            message_id: 100,
+            signal_name: "Temperature".to_string(),
+            value_type: SignalExtendedValueType::Float,
+        }];
+        let mut data = [1, 0, 0, 0, 0, 0, 0, 0];
+        data[4..].copy_from_slice(&(-25.0_f32).to_le_bytes());
+        let values = message.decode_frame_with_mux(&data, 2, &value_types);
+        assert_eq!(
+            values.get("Temperature"),
+            Some(&SignalValue::Physical(-2.5))
+        );
+        assert!(!values.contains_key("Level"));
+    }
+
+    #[test]
+    fn test_dbc_message_strict_01() {
+        let input = r#"BO_ 100 Engine: 8 ECU
+ SG_ Rpm : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+ SG_ Temperature : 16|8@1 (1,-40) [0|0] "degC" Vector__XXX
+ SG_ Load : 24|8@1+ (1,0) [0|100] "%" Vector__XXX
+"#;
+        let (remain, message) = parser_dbc_message(input).unwrap();
+        assert_eq!(message.signals.len(), 1);
+        assert!(remain.starts_with(" SG_ Temperature"));
+
+        assert_eq!(
+            parser_dbc_message_strict(input),
+            Err(nom::Err::Failure(DbcParseError::MalformedSignal(
+                r#"SG_ Temperature : 16|8@1 (1,-40) [0|0] "degC" Vector__XXX"#.into()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_dbc_message_strict_02() {
+        let (remain, messages) =
+            parser_dbc_messages_strict("BO_ 100 Engine: 8 ECU\n\nBO_TX_BU_ 100 : ECU;\n").unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(remain, "BO_TX_BU_ 100 : ECU;\n");
+
+        assert_eq!(
+            parser_dbc_messages_strict("BO_ 100 Engine: 8 ECU\n\nBO_ 200 Brakes 8 ECU\n"),
+            Err(nom::Err::Failure(DbcParseError::MalformedMessage(
+                "BO_ 200 Brakes 8 ECU".into()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_message_header_j1939_01() {
+        let header = |id| MessageHeader {
+            id,
+            name: "Msg".into(),
+            size: 8,
+            transmitter: "ECU".into(),
+        };
+        // EEC1 from engine #1
+        assert_eq!(
+            header(0x8CF0_0400).j1939(),
+            Some(J1939Id {
+                priority: 3,
+                pgn: 61444,
+                source_address: 0x00,
+            })
+        );
+        // PDU1 request to the global address, destination is not part of the PGN
+        assert_eq!(
+            header(0x98EA_FF17).j1939(),
+            Some(J1939Id {
+                priority: 6,
+                pgn: 59904,
+                source_address: 0x17,
+            })
+        );
+        assert_eq!(header(0x18FE_F100).j1939(), None);
+        assert_eq!(header(100).j1939(), None);
+    }
+
+    #[test]
+    fn test_message_likely_checksum_counter_signals_01() {
+        let (_, message) = parser_dbc_message(
+            r#"BO_ 100 Brake: 8 ECU
+ SG_ Brake_CRC : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+ SG_ Brake_AliveCounter : 8|4@1+ (1,0) [0|15] "" Vector__XXX
+ SG_ Pressure : 16|16@1+ (0.1,0) [0|0] "bar" Vector__XXX
+ SG_ Checksum_Status : 32|1@1+ (1,0) [0|1] "" Vector__XXX
+ SG_ TripCounter : 40|24@1+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let names = |signals: Vec<&Signal>| {
+            signals
+                .into_iter()
+                .map(|s| s.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(message.likely_checksum_signals()), vec!["Brake_CRC"]);
+        assert_eq!(
+            names(message.likely_counter_signals()),
+            vec!["Brake_AliveCounter"]
+        );
+    }
 }
@@ -0,0 +1,103 @@
+use std::fmt;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::map;
+use nom::multi::{many0, separated_list0};
+use nom::{IResult, Parser};
+
+use super::common_parsers::{multispacey, parser_message_id, parser_node_name, spacey};
+use super::error::DbcParseError;
+
+/// Message transmitters section, lists additional transmitters of a message for
+/// networks where a message can be sent by more than one node.
+///
+/// ```text
+/// message_transmitters = {message_transmitter} ;
+/// message_transmitter = 'BO_TX_BU_' message_id ':' {transmitter} ';' ;
+/// ```
+///
+/// example:
+///
+/// ```text
+/// BO_TX_BU_ 117 : ABS,Gateway;
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageTransmitters {
+    pub message_id: u32,
+    pub transmitters: Vec<String>,
+}
+
+impl fmt::Display for MessageTransmitters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BO_TX_BU_ {} : {};",
+            self.message_id,
+            self.transmitters.join(",")
+        )
+    }
+}
+
+pub fn parser_message_transmitters(
+    input: &str,
+) -> IResult<&str, MessageTransmitters, DbcParseError> {
+    let res = map(
+        (
+            multispacey(tag("BO_TX_BU_")),
+            spacey(parser_message_id),
+            spacey(tag(":")),
+            separated_list0(tag(","), spacey(parser_node_name)),
+            spacey(tag(";")),
+            many0(line_ending),
+        ),
+        |(_, message_id, _, transmitters, _, _)| MessageTransmitters {
+            message_id,
+            transmitters: transmitters.into_iter().map(String::from).collect(),
+        },
+    )
+    .parse(input);
+
+    match res {
+        Ok((remain, val)) => {
+            log::info!("parse message transmitters: {val:?}");
+            Ok((remain, val))
+        }
+        Err(e) => {
+            log::trace!("parse message transmitters failed, e = {e:?}");
+            Err(nom::Err::Error(DbcParseError::BadMessageTransmitters))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_transmitters_string_01() {
+        assert_eq!(
+            MessageTransmitters {
+                message_id: 117,
+                transmitters: vec!["ABS".to_string(), "Gateway".to_string()],
+            }
+            .to_string(),
+            "BO_TX_BU_ 117 : ABS,Gateway;"
+        );
+    }
+
+    #[test]
+    fn test_parser_message_transmitters_01() {
+        assert_eq!(
+            parser_message_transmitters("BO_TX_BU_ 117 : ABS, Gateway ;"),
+            Ok((
+                "",
+                MessageTransmitters {
+                    message_id: 117,
+                    transmitters: vec!["ABS".to_string(), "Gateway".to_string()],
+                }
+            ))
+        );
+    }
+}
@@ -10,12 +10,19 @@ pub mod env_var;
 pub mod env_var_data;
 pub mod env_var_value_descriptions;
 pub mod error;
+pub mod extended_multiplexing;
 pub mod message;
+pub mod message_transmitters;
 pub mod network_ast;
 pub mod new_symbols;
 pub mod nodes;
+pub mod raw_section;
+pub mod recovery;
 pub mod signal;
+pub mod signal_extended_value_type;
+pub mod signal_group;
 pub mod signal_value_descriptions;
+pub mod validation;
 pub mod value_descriptions;
 pub mod value_tables;
 pub mod version;
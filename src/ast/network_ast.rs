@@ -1,12 +1,18 @@
+use std::collections::{BTreeSet, HashMap};
 use std::fmt;
 
-use nom::combinator::{all_consuming, map};
+use nom::combinator::{all_consuming, map, opt};
 use nom::multi::many0;
 use nom::{IResult, Parser};
 
-use super::attribute_default::{parser_attribute_default, AttributeDefault};
-use super::attribute_definition::{parser_attribute_definition, AttributeDefinition};
-use super::attribute_value::{parser_object_attribute_value, ObjectAttributeValue};
+use super::attribute_default::{parser_attribute_default, AttributeDefault, AttributeValue};
+use super::attribute_definition::{
+    parser_attribute_definition, AttributeDefinition, AttributeObjectKind, AttributeValueType,
+};
+use super::attribute_value::{
+    parser_object_attribute_value, MessageAttributeValue, ObjectAttributeValue,
+    SignalAttributeValue,
+};
 use super::bit_timing::{parser_bit_timing, BitTiming};
 use super::comment::{parser_comment, Comment};
 use super::common_parsers::multispacey;
@@ -16,12 +22,27 @@ use super::env_var_value_descriptions::{
     parser_env_var_value_descriptions, EnvironmentVariableValueDescriptions,
 };
 use super::error::DbcParseError;
-use super::message::{parser_dbc_message, Message};
+use super::extended_multiplexing::{
+    parser_extended_multiplexing, ExtendedMultiplexing, MultiplexerValueRange,
+};
+use super::message::{parser_dbc_message, parser_dbc_messages_strict, Message, SendType};
+use super::message_transmitters::{parser_message_transmitters, MessageTransmitters};
 use super::new_symbols::{parser_new_symbols, NewSymbols};
-use super::nodes::{parser_nodes, Nodes};
+use super::nodes::{is_vector_xxx, parser_nodes, Nodes};
+use super::raw_section::{
+    parser_signal_type_attribute_value, parser_signal_type_value_descriptions, RawSection,
+};
+use super::recovery::parse_sections;
+use super::signal::{Signal, SignalValue};
+use super::signal_extended_value_type::{
+    parser_signal_extended_value_type, SignalExtendedValueType, SignalExtendedValueTypeList,
+};
+use super::signal_group::{parser_signal_group, SignalGroup};
 use super::signal_value_descriptions::{parser_signal_value_descriptions, SignalValueDescriptions};
+use super::value_descriptions::ValueDescriptions;
 use super::value_tables::{parser_value_tables, ValueTable};
 use super::version::{parser_version, Version};
+use crate::error::AttributeError;
 
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -44,6 +65,13 @@ pub struct NetworkAst {
     // BO_
     pub messages: Vec<Message>,
 
+    // BO_TX_BU_ message_id : transmitters;
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub message_transmitters: Vec<MessageTransmitters>,
+
     // EV_
     pub env_vars: Vec<EnvironmentVariable>,
 
@@ -62,20 +90,91 @@ pub struct NetworkAst {
     // BA_
     pub attribute_values: Vec<ObjectAttributeValue>,
 
+    // BA_SGTYPE_, kept as raw text
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub signal_type_attribute_values: Vec<RawSection>,
+
     // VAL_ message_id signal_name [value_descriptions];
     pub signal_value_descriptions: Vec<SignalValueDescriptions>,
 
     // VAL_ env_var_name [value_descriptions];
     pub env_var_value_descriptions: Vec<EnvironmentVariableValueDescriptions>,
+
+    // SGTYPE_VAL_, kept as raw text
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub signal_type_value_descriptions: Vec<RawSection>,
+
+    // SIG_GROUP_ message_id signal_group_name repetitions : {signal_name};
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub signal_groups: Vec<SignalGroup>,
+
+    // SIG_VALTYPE_ message_id signal_name : signal_extended_value_type;
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub signal_extended_value_types: Vec<SignalExtendedValueTypeList>,
+
+    // SG_MUL_VAL_ message_id signal_name multiplexer_switch_name value_ranges;
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub extended_multiplexings: Vec<ExtendedMultiplexing>,
 }
 
 impl fmt::Display for NetworkAst {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_dbc(f, FormatOptions::default())
+    }
+}
+
+/// Options for [`NetworkAst::to_dbc_with_options`]. The default is what
+/// [`NetworkAst::to_dbc`] does.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct FormatOptions {
+    /// Emit the `BS_:` line. The section is obsolete but the spec requires it, so it is
+    /// emitted by default. The parser accepts files without it either way.
+    pub emit_bit_timing: bool,
+    /// Line break written after every line.
+    pub line_ending: LineEnding,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            emit_bit_timing: true,
+            line_ending: LineEnding::Lf,
+        }
+    }
+}
+
+/// Line break style of [`FormatOptions::line_ending`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`, as Windows tools write it
+    CrLf,
+}
+
+impl NetworkAst {
+    fn write_dbc(&self, f: &mut impl fmt::Write, options: FormatOptions) -> fmt::Result {
         writeln!(f, "{}\n", self.version)?;
 
         writeln!(f, "{}", self.new_symbols)?;
 
-        if let Some(bc) = &self.bit_timing {
+        if let Some(bc) = self.bit_timing.as_ref().filter(|_| options.emit_bit_timing) {
             writeln!(f, "{bc}")?;
         }
 
@@ -92,6 +191,13 @@ impl fmt::Display for NetworkAst {
             writeln!(f, "{message}")?;
         }
 
+        for message_transmitters in &self.message_transmitters {
+            writeln!(f, "{message_transmitters}")?;
+        }
+        if !self.message_transmitters.is_empty() {
+            writeln!(f)?;
+        }
+
         for env_var in &self.env_vars {
             writeln!(f, "{env_var}")?;
         }
@@ -134,6 +240,13 @@ impl fmt::Display for NetworkAst {
             writeln!(f)?;
         }
 
+        for signal_type_attribute_value in &self.signal_type_attribute_values {
+            writeln!(f, "{signal_type_attribute_value}")?;
+        }
+        if !self.signal_type_attribute_values.is_empty() {
+            writeln!(f)?;
+        }
+
         for signal_value_description in &self.signal_value_descriptions {
             writeln!(f, "{signal_value_description}")?;
         }
@@ -144,27 +257,1081 @@ impl fmt::Display for NetworkAst {
         for env_var_value_description in &self.env_var_value_descriptions {
             writeln!(f, "{env_var_value_description}")?;
         }
+
+        for signal_type_value_description in &self.signal_type_value_descriptions {
+            writeln!(f, "{signal_type_value_description}")?;
+        }
+
+        if !self.signal_groups.is_empty() {
+            if !self.env_var_value_descriptions.is_empty()
+                || !self.signal_type_value_descriptions.is_empty()
+            {
+                writeln!(f)?;
+            }
+            for signal_group in &self.signal_groups {
+                writeln!(f, "{signal_group}")?;
+            }
+        }
+
+        if !self.signal_extended_value_types.is_empty() {
+            if !self.env_var_value_descriptions.is_empty()
+                || !self.signal_type_value_descriptions.is_empty()
+                || !self.signal_groups.is_empty()
+            {
+                writeln!(f)?;
+            }
+            for signal_extended_value_type in &self.signal_extended_value_types {
+                writeln!(f, "{signal_extended_value_type}")?;
+            }
+        }
+
+        if !self.extended_multiplexings.is_empty() {
+            if !self.env_var_value_descriptions.is_empty()
+                || !self.signal_type_value_descriptions.is_empty()
+                || !self.signal_groups.is_empty()
+                || !self.signal_extended_value_types.is_empty()
+            {
+                writeln!(f)?;
+            }
+            for extended_multiplexing in &self.extended_multiplexings {
+                writeln!(f, "{extended_multiplexing}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl NetworkAst {
+    /// The first message with the ID `id`.
+    pub fn message_by_id(&self, id: u32) -> Option<&Message> {
+        self.messages.iter().find(|m| m.header.id == id)
+    }
+
+    /// The IDs of all messages as written in `BO_`, sorted and without duplicates.
+    pub fn message_ids(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self.messages.iter().map(|m| m.header.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// The CAN IDs of all messages, without the extended flag (see
+    /// [`MessageHeader::can_id`](super::message::MessageHeader::can_id)), sorted and
+    /// without duplicates. The [`INDEPENDENT_SIG_MSG`](super::message::INDEPENDENT_SIG_MSG)
+    /// pseudo message is left out, as it is not sent on the bus.
+    pub fn can_ids(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self
+            .messages
+            .iter()
+            .filter(|m| !m.header.is_independent_sig_msg())
+            .map(|m| m.header.can_id())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// IDs of the messages with a signal named `name`, in message order. A message whose
+    /// multiplex groups each define the name is listed once.
+    pub fn messages_containing_signal(&self, name: &str) -> Vec<u32> {
+        let mut ids = vec![];
+        for (message, _) in self.find_signals(name) {
+            if !ids.contains(&message.header.id) {
+                ids.push(message.header.id);
+            }
+        }
+        ids
+    }
+
+    /// All signals named `name`, with their message. Signal names are only unique within
+    /// a message.
+    pub fn find_signals(&self, name: &str) -> Vec<(&Message, &Signal)> {
+        self.messages
+            .iter()
+            .flat_map(|message| {
+                message
+                    .signals
+                    .iter()
+                    .filter(|signal| signal.name == name)
+                    .map(move |signal| (message, signal))
+            })
+            .collect()
+    }
+
+    /// Messages transmitted by `node`, either as the message's transmitter or as one of
+    /// its additional transmitters (`BO_TX_BU_`). The `Vector__XXX` placeholder transmits
+    /// nothing.
+    pub fn messages_from(&self, node: &str) -> Vec<&Message> {
+        if is_vector_xxx(node) {
+            return vec![];
+        }
+        self.messages
+            .iter()
+            .filter(|message| {
+                message.header.transmitter == node
+                    || self.message_transmitters.iter().any(|tx| {
+                        tx.message_id == message.header.id
+                            && tx.transmitters.iter().any(|t| t == node)
+                    })
+            })
+            .collect()
+    }
+
+    /// Signals received by `node`, with their message. The `Vector__XXX` placeholder
+    /// receives nothing.
+    pub fn signals_received_by(&self, node: &str) -> Vec<(&Message, &Signal)> {
+        if is_vector_xxx(node) {
+            return vec![];
+        }
+        self.messages
+            .iter()
+            .flat_map(|message| message.signals.iter().map(move |signal| (message, signal)))
+            .filter(|(_, signal)| {
+                signal
+                    .receivers
+                    .as_ref()
+                    .is_some_and(|r| r.iter().any(|r| r == node))
+            })
+            .collect()
+    }
+
+    /// Messages having a signal named `sig_name`, in file order. Signal names are only
+    /// unique within a message, so there may be several.
+    pub fn message_of_signal(&self, sig_name: &str) -> Vec<&Message> {
+        let mut messages: Vec<&Message> = vec![];
+        for (message, _) in self.find_signals(sig_name) {
+            if !messages.iter().any(|m| std::ptr::eq(*m, message)) {
+                messages.push(message);
+            }
+        }
+        messages
+    }
+
+    /// Signal groups (`SIG_GROUP_`) of the message `id`. Groups are a network section,
+    /// so they are looked up here rather than on [`Message`].
+    pub fn signal_groups(&self, id: u32) -> Vec<&SignalGroup> {
+        self.signal_groups
+            .iter()
+            .filter(|group| group.message_id == id)
+            .collect()
+    }
+
+    /// Every non-empty signal unit used in the network.
+    pub fn units(&self) -> BTreeSet<String> {
+        self.messages
+            .iter()
+            .flat_map(|message| &message.signals)
+            .filter_map(|signal| signal.unit.as_ref())
+            .filter(|unit| !unit.0.is_empty())
+            .map(|unit| unit.0.clone())
+            .collect()
+    }
+
+    /// Value tables (`VAL_TABLE_`) keyed by name, empty when the section is absent.
+    pub fn value_table_map(&self) -> HashMap<&str, &ValueDescriptions> {
+        self.value_tables
+            .iter()
+            .flatten()
+            .map(|table| (table.name.as_str(), &table.value_descriptions))
+            .collect()
+    }
+
+    /// Serialize the network to DBC text.
+    ///
+    /// This is the same text as the [`fmt::Display`] implementation and can be read back
+    /// with [`parse_dbc`].
+    ///
+    /// The original spacing is not kept: every statement is written in a canonical form
+    /// with single spaces, e.g. `BO_ 1  Foo :  8  Node` becomes `BO_ 1 Foo: 8 Node` and
+    /// `BA_DEF_  "X" INT 0 1;` becomes `BA_DEF_ "X" INT 0 1;`. Formatting is idempotent
+    /// after the first pass, so parsing and formatting the output again yields the same
+    /// text, which is what byte-exact diffs of formatted files can rely on.
+    pub fn to_dbc(&self) -> String {
+        self.to_string()
+    }
+
+    /// Serialize the network to DBC text, formatted according to `options`.
+    pub fn to_dbc_with_options(&self, options: FormatOptions) -> String {
+        let mut out = String::new();
+        let _ = self.write_dbc(&mut out, options);
+        match options.line_ending {
+            LineEnding::Lf => out,
+            LineEnding::CrLf => {
+                let mut crlf = String::with_capacity(out.len() + out.len() / 16);
+                let mut prev = None;
+                for c in out.chars() {
+                    // strings parsed from CRLF files already contain `\r\n`
+                    if c == '\n' && prev != Some('\r') {
+                        crlf.push('\r');
+                    }
+                    crlf.push(c);
+                    prev = Some(c);
+                }
+                crlf
+            }
+        }
+    }
+
+    /// Sort the network into a canonical order: messages by ID, signals by start bit,
+    /// multiplexer indicator and name, nodes alphabetically, attribute definitions by
+    /// name and the statements of every other section by their DBC text.
+    ///
+    /// Two networks that only differ in the order of their statements display
+    /// identically after normalizing. This is not done by the parser, which keeps the
+    /// order of the input.
+    pub fn normalize(&mut self) {
+        self.new_symbols.0.sort();
+        self.nodes.0.sort();
+        self.messages.sort_by_key(|message| message.header.id);
+        for message in &mut self.messages {
+            message.signals.sort_by(signal_order);
+        }
+        self.attribute_definitions
+            .sort_by(|a, b| a.attribute_name().cmp(b.attribute_name()));
+        if let Some(value_tables) = &mut self.value_tables {
+            sort_by_text(value_tables);
+        }
+        sort_by_text(&mut self.message_transmitters);
+        sort_by_text(&mut self.env_vars);
+        sort_by_text(&mut self.env_vars_data);
+        sort_by_text(&mut self.comments);
+        sort_by_text(&mut self.attribute_defaults);
+        sort_by_text(&mut self.attribute_values);
+        sort_by_text(&mut self.signal_type_attribute_values);
+        sort_by_text(&mut self.signal_value_descriptions);
+        sort_by_text(&mut self.env_var_value_descriptions);
+        sort_by_text(&mut self.signal_type_value_descriptions);
+        sort_by_text(&mut self.signal_groups);
+        sort_by_text(&mut self.signal_extended_value_types);
+        sort_by_text(&mut self.extended_multiplexings);
+    }
+
+    /// Give every signal without a range, i.e. without `[min|max]` or with the `[0|0]`
+    /// written for "no range", the range its bit width, factor and offset imply (see
+    /// [`Signal::computed_range`]), for consumers that need explicit ranges. Declared
+    /// ranges are kept.
+    pub fn fill_missing_ranges(&mut self) {
+        for signal in self.messages.iter_mut().flat_map(|m| &mut m.signals) {
+            if matches!((signal.min, signal.max), (Some(min), Some(max)) if min != 0.0 || max != 0.0)
+            {
+                continue;
+            }
+            let (min, max) = signal.computed_range();
+            signal.min = Some(min);
+            signal.max = Some(max);
+        }
+    }
+
+    /// Rename the signal `old` of the message `msg_id` to `new`, along with every
+    /// reference to it: comments (`CM_ SG_`), attribute values (`BA_ ... SG_` and
+    /// `BU_SG_REL_`), value descriptions (`VAL_`), extended value types (`SIG_VALTYPE_`),
+    /// extended multiplexing (`SG_MUL_VAL_`) and signal groups (`SIG_GROUP_`).
+    ///
+    /// Returns whether the signal was renamed. Nothing is changed if the signal does not
+    /// exist or if its message already has a signal named `new`, which would make the
+    /// references ambiguous.
+    pub fn rename_signal(&mut self, msg_id: u32, old: &str, new: &str) -> bool {
+        let Some(message) = self
+            .messages
+            .iter_mut()
+            .filter(|m| m.header.id == msg_id)
+            .find(|m| m.signals.iter().any(|s| s.name == old))
+        else {
+            return false;
+        };
+        if old != new && message.signals.iter().any(|s| s.name == new) {
+            return false;
+        }
+        if let Some(signal) = message.signals.iter_mut().find(|s| s.name == old) {
+            signal.name = new.to_string();
+        }
+
+        let rename = |message_id: u32, name: &mut String| {
+            if message_id == msg_id && name == old {
+                *name = new.to_string();
+            }
+        };
+        for comment in &mut self.comments {
+            if let Comment::Signal(c) = comment {
+                rename(c.message_id, &mut c.signal_name);
+            }
+        }
+        for value in &mut self.attribute_values {
+            match value {
+                ObjectAttributeValue::Signal(v) => rename(v.message_id, &mut v.signal_name),
+                ObjectAttributeValue::NodeMappedRxSignal(v) => {
+                    rename(v.message_id, &mut v.signal_name);
+                }
+                _ => {}
+            }
+        }
+        for v in &mut self.signal_value_descriptions {
+            rename(v.message_id, &mut v.signal_name);
+        }
+        for v in &mut self.signal_extended_value_types {
+            rename(v.message_id, &mut v.signal_name);
+        }
+        for m in &mut self.extended_multiplexings {
+            rename(m.message_id, &mut m.signal_name);
+            rename(m.message_id, &mut m.multiplexer_switch_name);
+        }
+        for group in &mut self.signal_groups {
+            for name in &mut group.signal_names {
+                rename(group.message_id, name);
+            }
+        }
+        true
+    }
+
+    /// Hash of the network's content, ignoring formatting and the order of messages,
+    /// signals, nodes and of the statements within each section.
+    ///
+    /// Two networks that only differ in whitespace or statement order hash the same, so
+    /// caches and diff tools can tell that nothing really changed. Floats are hashed in
+    /// their shortest round-trip form, which identifies their bit pattern. The hash is
+    /// FNV-1a and does not depend on the platform or Rust version.
+    pub fn semantic_hash(&self) -> u64 {
+        let mut network = self.clone();
+        network.normalize();
+        let mut new_symbols = network.new_symbols.0.clone();
+        new_symbols.sort();
+        let sections = [
+            vec![network.version.to_string()],
+            new_symbols,
+            network.bit_timing.iter().map(ToString::to_string).collect(),
+            vec![network.nodes.to_string()],
+            sorted_strings(network.value_tables.iter().flatten()),
+            sorted_strings(&network.messages),
+            sorted_strings(&network.message_transmitters),
+            sorted_strings(&network.env_vars),
+            sorted_strings(&network.env_vars_data),
+            sorted_strings(&network.comments),
+            sorted_strings(&network.attribute_definitions),
+            sorted_strings(&network.attribute_defaults),
+            sorted_strings(&network.attribute_values),
+            sorted_strings(&network.signal_type_attribute_values),
+            sorted_strings(&network.signal_value_descriptions),
+            sorted_strings(&network.env_var_value_descriptions),
+            sorted_strings(&network.signal_type_value_descriptions),
+            sorted_strings(&network.signal_groups),
+            sorted_strings(&network.signal_extended_value_types),
+            sorted_strings(&network.extended_multiplexings),
+        ];
+        let mut hash = FNV_OFFSET_BASIS;
+        for section in sections {
+            for item in section {
+                hash = fnv1a(hash, item.as_bytes());
+                hash = fnv1a(hash, b"\n");
+            }
+            // keeps an item from hashing the same when it moves to a neighbouring section
+            hash = fnv1a(hash, b"\0");
+        }
+        hash
+    }
+
+    /// Attribute definitions (`BA_DEF_`, `BA_DEF_REL_`) for objects of `kind`, in file
+    /// order.
+    pub fn attribute_definitions_for(
+        &self,
+        kind: AttributeObjectKind,
+    ) -> Vec<&AttributeDefinition> {
+        self.attribute_definitions
+            .iter()
+            .filter(|definition| definition.object_kind() == kind)
+            .collect()
+    }
+
+    /// Names of attributes that are given a value (`BA_`) or default (`BA_DEF_DEF_`) but
+    /// have no definition (`BA_DEF_`), each once: those used in `BA_` first, in file order.
+    pub fn undefined_attributes(&self) -> Vec<String> {
+        let mut undefined: Vec<String> = vec![];
+        let used = self
+            .attribute_values
+            .iter()
+            .map(ObjectAttributeValue::attribute_name)
+            .chain(
+                self.attribute_defaults
+                    .iter()
+                    .map(AttributeDefault::attribute_name),
+            );
+        for name in used {
+            let defined = self
+                .attribute_definitions
+                .iter()
+                .any(|definition| definition.attribute_name() == name);
+            if !defined && !undefined.iter().any(|n| n == name) {
+                undefined.push(name.to_string());
+            }
+        }
+        undefined
+    }
+
+    /// The node and the object a relation attribute value (`BA_REL_`) refers to, or
+    /// `None` if `value` is not a relation value or one of them is not in the network.
+    pub fn relation_target(&self, value: &ObjectAttributeValue) -> Option<RelationTarget<'_>> {
+        let node = |name: &str| self.nodes.0.iter().find(|n| *n == name).map(String::as_str);
+        match value {
+            ObjectAttributeValue::ControlUnitEnvironmentVariable(v) => {
+                Some(RelationTarget::EnvironmentVariable {
+                    node: node(&v.node_name)?,
+                    env_var: self
+                        .env_vars
+                        .iter()
+                        .find(|e| e.env_var_name == v.env_var_name)?,
+                })
+            }
+            ObjectAttributeValue::NodeTxMessage(v) => Some(RelationTarget::Message {
+                node: node(&v.node_name)?,
+                message: self.message_by_id(v.message_id)?,
+            }),
+            ObjectAttributeValue::NodeMappedRxSignal(v) => {
+                let message = self.message_by_id(v.message_id)?;
+                Some(RelationTarget::Signal {
+                    node: node(&v.node_name)?,
+                    message,
+                    signal: message.signals.iter().find(|s| s.name == v.signal_name)?,
+                })
+            }
+            ObjectAttributeValue::Network(_)
+            | ObjectAttributeValue::Node(_)
+            | ObjectAttributeValue::Message(_)
+            | ObjectAttributeValue::Signal(_)
+            | ObjectAttributeValue::EnvironmentVariable(_) => None,
+        }
+    }
+
+    /// Default value (`BA_DEF_DEF_`) of the attribute named `name`.
+    pub fn attribute_default(&self, name: &str) -> Option<&AttributeValue> {
+        self.attribute_defaults
+            .iter()
+            .find(|default| default.attribute_name() == name)
+            .map(AttributeDefault::attribute_value)
+    }
+
+    /// Read quoted-number defaults (`BA_DEF_DEF_ "name" "5";`) of INT, HEX and FLOAT
+    /// attributes as numbers, see [`AttributeValueType::resolve`]. Done by every parse
+    /// function.
+    pub fn resolve_attribute_defaults(&mut self) {
+        for default in &mut self.attribute_defaults {
+            let Some(definition) = self
+                .attribute_definitions
+                .iter()
+                .find(|d| d.attribute_name() == default.attribute_name())
+            else {
+                continue;
+            };
+            let value = default.attribute_value_mut();
+            *value = definition.attribute_value_type().resolve(value.clone());
+        }
+    }
+
+    /// Attribute values (`BA_ "name" BO_ id value;`) set on the message `id`.
+    pub fn message_attributes(&self, id: u32) -> Vec<(&str, &AttributeValue)> {
+        self.attribute_values
+            .iter()
+            .filter_map(|value| match value {
+                ObjectAttributeValue::Message(v) if v.message_id == id => {
+                    Some((v.attribute_name.as_str(), &v.attribute_value))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Same as [`NetworkAst::message_attributes`], followed by the defaults of the message
+    /// attributes that are not set on the message.
+    pub fn message_attributes_with_defaults(&self, id: u32) -> Vec<(&str, &AttributeValue)> {
+        let mut attributes = self.message_attributes(id);
+        for definition in &self.attribute_definitions {
+            let AttributeDefinition::Message(definition) = definition else {
+                continue;
+            };
+            let name = definition.attribute_name.as_str();
+            if attributes.iter().any(|(n, _)| *n == name) {
+                continue;
+            }
+            if let Some(value) = self.attribute_default(name) {
+                attributes.push((name, value));
+            }
+        }
+        attributes
+    }
+
+    /// Value of the attribute `name` set on the signal `signal_name` of the message
+    /// `message_id` (`BA_ "name" SG_ id signal value;`), or the attribute's default.
+    pub fn signal_attribute_value(
+        &self,
+        message_id: u32,
+        signal_name: &str,
+        name: &str,
+    ) -> Option<&AttributeValue> {
+        self.attribute_values
+            .iter()
+            .find_map(|value| match value {
+                ObjectAttributeValue::Signal(v)
+                    if v.message_id == message_id
+                        && v.signal_name == signal_name
+                        && v.attribute_name == name =>
+                {
+                    Some(&v.attribute_value)
+                }
+                _ => None,
+            })
+            .or_else(|| self.attribute_default(name))
+    }
+
+    /// Set the attribute `name` of the message `id` to `value`, replacing the message's
+    /// existing `BA_` for it or appending a new one.
+    ///
+    /// The attribute must be defined for messages (`BA_DEF_ BO_`) and `value` must match
+    /// its type.
+    pub fn set_message_attribute(
+        &mut self,
+        id: u32,
+        name: &str,
+        value: AttributeValue,
+    ) -> Result<(), AttributeError> {
+        self.check_attribute_value(name, &value, |d| {
+            matches!(d, AttributeDefinition::Message(_))
+        })?;
+        for existing in &mut self.attribute_values {
+            if let ObjectAttributeValue::Message(v) = existing {
+                if v.message_id == id && v.attribute_name == name {
+                    v.attribute_value = value;
+                    return Ok(());
+                }
+            }
+        }
+        self.attribute_values
+            .push(ObjectAttributeValue::Message(MessageAttributeValue {
+                attribute_name: name.to_string(),
+                message_id: id,
+                attribute_value: value,
+            }));
+        Ok(())
+    }
+
+    /// Set the attribute `name` of the signal `signal_name` of the message `message_id`,
+    /// like [`NetworkAst::set_message_attribute`]. The attribute must be defined for
+    /// signals (`BA_DEF_ SG_`).
+    pub fn set_signal_attribute(
+        &mut self,
+        message_id: u32,
+        signal_name: &str,
+        name: &str,
+        value: AttributeValue,
+    ) -> Result<(), AttributeError> {
+        self.check_attribute_value(name, &value, |d| {
+            matches!(d, AttributeDefinition::Signal(_))
+        })?;
+        for existing in &mut self.attribute_values {
+            if let ObjectAttributeValue::Signal(v) = existing {
+                if v.message_id == message_id
+                    && v.signal_name == signal_name
+                    && v.attribute_name == name
+                {
+                    v.attribute_value = value;
+                    return Ok(());
+                }
+            }
+        }
+        self.attribute_values
+            .push(ObjectAttributeValue::Signal(SignalAttributeValue {
+                attribute_name: name.to_string(),
+                message_id,
+                signal_name: signal_name.to_string(),
+                attribute_value: value,
+            }));
         Ok(())
     }
+
+    /// Check that an attribute `name` is defined by a definition `is_kind` accepts, and
+    /// that `value` matches its type.
+    fn check_attribute_value(
+        &self,
+        name: &str,
+        value: &AttributeValue,
+        is_kind: impl Fn(&AttributeDefinition) -> bool,
+    ) -> Result<(), AttributeError> {
+        let definition = self
+            .attribute_definitions
+            .iter()
+            .find(|d| is_kind(d) && d.attribute_name() == name)
+            .ok_or_else(|| AttributeError::Undefined(name.to_string()))?;
+        if definition.attribute_value_type().accepts(value) {
+            Ok(())
+        } else {
+            Err(AttributeError::TypeMismatch(name.to_string()))
+        }
+    }
+
+    /// How the message `id` is sent, from its `GenMsgSendType` attribute (a string or an
+    /// ENUM index) or its default. Without a send type, a `GenMsgCycleTime` above 0 makes
+    /// the message [`SendType::Cyclic`].
+    pub fn send_type(&self, id: u32) -> SendType {
+        let attributes = self.message_attributes_with_defaults(id);
+        let attribute = |name: &str| attributes.iter().find(|(n, _)| *n == name).map(|(_, v)| *v);
+        if let Some(value) = attribute("GenMsgSendType") {
+            return self
+                .attribute_label("GenMsgSendType", value)
+                .map_or(SendType::Unknown, SendType::from_attribute);
+        }
+        match attribute("GenMsgCycleTime") {
+            Some(AttributeValue::Double(cycle_time)) if *cycle_time > 0.0 => SendType::Cyclic,
+            _ => SendType::Unknown,
+        }
+    }
+
+    /// Text of an attribute value: the string itself, or the label an ENUM index refers
+    /// to.
+    fn attribute_label<'a>(&'a self, name: &str, value: &'a AttributeValue) -> Option<&'a str> {
+        match value {
+            AttributeValue::String(s) => Some(&s.0),
+            AttributeValue::Double(index) => {
+                let definition = self
+                    .attribute_definitions
+                    .iter()
+                    .find(|d| d.attribute_name() == name)?;
+                let AttributeValueType::Enum(enum_type) = definition.attribute_value_type() else {
+                    return None;
+                };
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let index = *index as usize;
+                enum_type.values.get(index).map(|v| v.0.as_str())
+            }
+        }
+    }
+
+    /// Start value of a signal, from its `GenSigStartValue` attribute or the attribute's
+    /// default, as it is written in the file.
+    ///
+    /// Vector tools and most others store `GenSigStartValue` in raw units, i.e. before
+    /// `factor` and `offset` are applied. Use [`NetworkAst::signal_start_value`] for the
+    /// physical value.
+    pub fn signal_start_value_raw(&self, message_id: u32, signal_name: &str) -> Option<f64> {
+        match self.signal_attribute_value(message_id, signal_name, "GenSigStartValue")? {
+            AttributeValue::Double(raw) => Some(*raw),
+            AttributeValue::String(_) => None,
+        }
+    }
+
+    /// Physical start value of a signal: [`NetworkAst::signal_start_value_raw`] scaled by
+    /// the signal's `factor` and `offset`.
+    pub fn signal_start_value(&self, message_id: u32, signal_name: &str) -> Option<f64> {
+        let raw = self.signal_start_value_raw(message_id, signal_name)?;
+        let signal = self
+            .message_by_id(message_id)?
+            .signals
+            .iter()
+            .find(|s| s.name == signal_name)?;
+        Some(signal.raw_to_physical(raw))
+    }
+
+    /// Extended value type (`SIG_VALTYPE_`) of a signal, [`SignalExtendedValueType::Integer`]
+    /// if the signal has none.
+    pub fn signal_extended_value_type(
+        &self,
+        message_id: u32,
+        signal_name: &str,
+    ) -> SignalExtendedValueType {
+        self.signal_extended_value_types
+            .iter()
+            .find(|v| v.message_id == message_id && v.signal_name == signal_name)
+            .map_or(SignalExtendedValueType::Integer, |v| v.value_type)
+    }
+
+    /// Decode the physical value of a signal of the message `message_id` from `data`,
+    /// taking the signal's extended value type into account.
+    pub fn decode_signal(&self, message_id: u32, signal_name: &str, data: &[u8]) -> Option<f64> {
+        let signal = self
+            .message_by_id(message_id)?
+            .signals
+            .iter()
+            .find(|s| s.name == signal_name)?;
+        signal.decode(
+            data,
+            self.signal_extended_value_type(message_id, signal_name),
+        )
+    }
+
+    /// Decode the signals of the message `message_id` that are present in `data`.
+    ///
+    /// A multiplexed signal is only decoded if its multiplexer switch is itself present and
+    /// holds a matching raw value. The switch and the values come from the signal's
+    /// `SG_MUL_VAL_` entries if it has any, otherwise `m<value>` selects on the message's
+    /// top level `M` switch. Nested switches (`m<value>M`) are resolved the same way, so
+    /// the whole multiplexer tree is walked from the top down.
+    ///
+    /// A signal whose raw value has a value description (`VAL_`) is decoded to that
+    /// [`SignalValue::Label`], any other to its [`SignalValue::Physical`] value.
+    pub fn decode_frame(&self, message_id: u32, data: &[u8]) -> Option<Vec<(&str, SignalValue)>> {
+        let message = self.message_by_id(message_id)?;
+        let mut present = vec![None; message.signals.len()];
+        let mut values = vec![];
+        for (index, signal) in message.signals.iter().enumerate() {
+            if !self.is_signal_present(message, index, data, &mut present, 0) {
+                continue;
+            }
+            if let Some(value) = self.decode_signal_value(message_id, signal, data) {
+                values.push((signal.name.as_str(), value));
+            }
+        }
+        Some(values)
+    }
+
+    /// Value of `signal` of the message `message_id` in `data`, labelled as in
+    /// [`NetworkAst::decode_frame`].
+    fn decode_signal_value(
+        &self,
+        message_id: u32,
+        signal: &Signal,
+        data: &[u8],
+    ) -> Option<SignalValue> {
+        let extended_value_type = self.signal_extended_value_type(message_id, &signal.name);
+        let physical = signal.decode(data, extended_value_type)?;
+        let label = self
+            .signal_value_descriptions
+            .iter()
+            .find(|v| v.message_id == message_id && v.signal_name == signal.name)
+            .and_then(|v| {
+                let raw = signal.decode_raw_signed(data)?;
+                v.value_descriptions
+                    .values
+                    .iter()
+                    .find(|item| item.num == raw)
+            });
+        Some(match label {
+            Some(item) => SignalValue::Label(item.str.0.clone()),
+            None => SignalValue::Physical(physical),
+        })
+    }
+
+    /// Decode a sequence of frames into one column per signal, for telemetry and analysis.
+    ///
+    /// Each column has one entry per frame, in frame order: the signal's value as decoded by
+    /// [`NetworkAst::decode_frame`], or `None` if that frame did not carry the signal.
+    /// Every signal of the network gets a column, keyed by message ID and signal name, so
+    /// signals of the same name in different messages (`Counter`, `CRC`, ...) stay apart.
+    /// Frames of unknown messages leave every column `None`.
+    pub fn decode_log(
+        &self,
+        frames: &[(u32, Vec<u8>)],
+    ) -> HashMap<(u32, String), Vec<Option<SignalValue>>> {
+        let mut columns: HashMap<(u32, String), Vec<Option<SignalValue>>> = self
+            .messages
+            .iter()
+            .flat_map(|m| m.signals.iter().map(|s| (m.header.id, s)))
+            .map(|(id, s)| ((id, s.name.clone()), vec![None; frames.len()]))
+            .collect();
+        for (index, (message_id, data)) in frames.iter().enumerate() {
+            for (name, value) in self.decode_frame(*message_id, data).unwrap_or_default() {
+                if let Some(column) = columns.get_mut(&(*message_id, name.to_string())) {
+                    column[index] = Some(value);
+                }
+            }
+        }
+        columns
+    }
+
+    /// DBC text of the message `id` with the statements that refer to it: its `BO_` and
+    /// `SG_` block followed by its `BO_TX_BU_`, comments, attribute values, value
+    /// descriptions, signal groups, extended value types and extended multiplexing, for
+    /// documentation, bug reports and focused review.
+    ///
+    /// Returns `None` if there is no message `id`. The text is not a complete DBC file.
+    pub fn format_message_block(&self, id: u32) -> Option<String> {
+        fn lines<T: fmt::Display>(items: impl Iterator<Item = T>) -> Vec<String> {
+            items.map(|item| item.to_string()).collect()
+        }
+
+        let message = self.message_by_id(id)?;
+        let sections = [
+            lines(
+                self.message_transmitters
+                    .iter()
+                    .filter(|tx| tx.message_id == id),
+            ),
+            lines(self.comments.iter().filter(|comment| match comment {
+                Comment::Message(c) => c.message_id == id,
+                Comment::Signal(c) => c.message_id == id,
+                Comment::Network(_) | Comment::Node(_) | Comment::EnvironmentVariable(_) => false,
+            })),
+            lines(self.attribute_values.iter().filter(|value| match value {
+                ObjectAttributeValue::Message(v) => v.message_id == id,
+                ObjectAttributeValue::Signal(v) => v.message_id == id,
+                ObjectAttributeValue::NodeTxMessage(v) => v.message_id == id,
+                ObjectAttributeValue::NodeMappedRxSignal(v) => v.message_id == id,
+                ObjectAttributeValue::Network(_)
+                | ObjectAttributeValue::Node(_)
+                | ObjectAttributeValue::EnvironmentVariable(_)
+                | ObjectAttributeValue::ControlUnitEnvironmentVariable(_) => false,
+            })),
+            lines(
+                self.signal_value_descriptions
+                    .iter()
+                    .filter(|v| v.message_id == id),
+            ),
+            lines(self.signal_groups.iter().filter(|v| v.message_id == id)),
+            lines(
+                self.signal_extended_value_types
+                    .iter()
+                    .filter(|v| v.message_id == id),
+            ),
+            lines(
+                self.extended_multiplexings
+                    .iter()
+                    .filter(|v| v.message_id == id),
+            ),
+        ];
+
+        let mut block = message.to_string();
+        for section in sections.iter().filter(|section| !section.is_empty()) {
+            block.push('\n');
+            for line in section {
+                block.push_str(line);
+                block.push('\n');
+            }
+        }
+        Some(block)
+    }
+
+    /// A copy of the network with only the messages `node` transmits or receives, for
+    /// generating a per-ECU database from a bus-wide one.
+    ///
+    /// Nodes are pruned to `node` and the other transmitters and receivers of those
+    /// messages. Comments, attribute values and other per-object sections are pruned to
+    /// the remaining nodes and messages. Global sections such as the version, value tables,
+    /// attribute definitions and environment variables are kept as they are.
+    #[must_use]
+    pub fn subset_for_node(&self, node: &str) -> NetworkAst {
+        let sent: Vec<u32> = self
+            .messages_from(node)
+            .iter()
+            .map(|m| m.header.id)
+            .collect();
+        let received: Vec<u32> = self
+            .signals_received_by(node)
+            .iter()
+            .map(|(m, _)| m.header.id)
+            .collect();
+        let messages: Vec<Message> = self
+            .messages
+            .iter()
+            .filter(|message| {
+                sent.contains(&message.header.id) || received.contains(&message.header.id)
+            })
+            .cloned()
+            .collect();
+        let has_message = |id: u32| messages.iter().any(|m| m.header.id == id);
+        let message_transmitters: Vec<MessageTransmitters> = self
+            .message_transmitters
+            .iter()
+            .filter(|tx| has_message(tx.message_id))
+            .cloned()
+            .collect();
+
+        let nodes: Vec<String> = self
+            .nodes
+            .0
+            .iter()
+            .filter(|name| {
+                *name == node
+                    || messages.iter().any(|m| {
+                        m.header.transmitter == **name
+                            || m.signals
+                                .iter()
+                                .any(|s| s.receivers.as_ref().is_some_and(|r| r.contains(name)))
+                    })
+                    || message_transmitters
+                        .iter()
+                        .any(|tx| tx.transmitters.contains(name))
+            })
+            .cloned()
+            .collect();
+        let has_node = |name: &str| nodes.iter().any(|n| n == name);
+
+        NetworkAst {
+            version: self.version.clone(),
+            new_symbols: self.new_symbols.clone(),
+            bit_timing: self.bit_timing.clone(),
+            value_tables: self.value_tables.clone(),
+            env_vars: self.env_vars.clone(),
+            env_vars_data: self.env_vars_data.clone(),
+            comments: self
+                .comments
+                .iter()
+                .filter(|comment| match comment {
+                    Comment::Node(c) => has_node(&c.node_name),
+                    Comment::Message(c) => has_message(c.message_id),
+                    Comment::Signal(c) => has_message(c.message_id),
+                    Comment::Network(_) | Comment::EnvironmentVariable(_) => true,
+                })
+                .cloned()
+                .collect(),
+            attribute_definitions: self.attribute_definitions.clone(),
+            attribute_defaults: self.attribute_defaults.clone(),
+            attribute_values: self
+                .attribute_values
+                .iter()
+                .filter(|value| match value {
+                    ObjectAttributeValue::Node(v) => has_node(&v.node_name),
+                    ObjectAttributeValue::Message(v) => has_message(v.message_id),
+                    ObjectAttributeValue::Signal(v) => has_message(v.message_id),
+                    ObjectAttributeValue::Network(_)
+                    | ObjectAttributeValue::EnvironmentVariable(_) => true,
+                    ObjectAttributeValue::ControlUnitEnvironmentVariable(v) => {
+                        has_node(&v.node_name)
+                    }
+                    ObjectAttributeValue::NodeTxMessage(v) => {
+                        has_node(&v.node_name) && has_message(v.message_id)
+                    }
+                    ObjectAttributeValue::NodeMappedRxSignal(v) => {
+                        has_node(&v.node_name) && has_message(v.message_id)
+                    }
+                })
+                .cloned()
+                .collect(),
+            signal_value_descriptions: self
+                .signal_value_descriptions
+                .iter()
+                .filter(|v| has_message(v.message_id))
+                .cloned()
+                .collect(),
+            env_var_value_descriptions: self.env_var_value_descriptions.clone(),
+            signal_type_attribute_values: self.signal_type_attribute_values.clone(),
+            signal_type_value_descriptions: self.signal_type_value_descriptions.clone(),
+            signal_groups: self
+                .signal_groups
+                .iter()
+                .filter(|v| has_message(v.message_id))
+                .cloned()
+                .collect(),
+            signal_extended_value_types: self
+                .signal_extended_value_types
+                .iter()
+                .filter(|v| has_message(v.message_id))
+                .cloned()
+                .collect(),
+            extended_multiplexings: self
+                .extended_multiplexings
+                .iter()
+                .filter(|v| has_message(v.message_id))
+                .cloned()
+                .collect(),
+            nodes: Nodes(nodes),
+            message_transmitters,
+            messages,
+        }
+    }
+
+    fn is_signal_present(
+        &self,
+        message: &Message,
+        index: usize,
+        data: &[u8],
+        present: &mut [Option<bool>],
+        depth: usize,
+    ) -> bool {
+        if let Some(p) = present[index] {
+            return p;
+        }
+        let signal = &message.signals[index];
+        let Some(value) = signal
+            .multiplexer
+            .as_ref()
+            .and_then(|m| m.multiplexer_signal)
+        else {
+            return true;
+        };
+        // a switch cycle can't be resolved
+        if depth > message.signals.len() {
+            return false;
+        }
+
+        let mut selectors: Vec<(&str, &[MultiplexerValueRange])> = self
+            .extended_multiplexings
+            .iter()
+            .filter(|m| m.message_id == message.header.id && m.signal_name == signal.name)
+            .map(|m| {
+                (
+                    m.multiplexer_switch_name.as_str(),
+                    m.value_ranges.as_slice(),
+                )
+            })
+            .collect();
+        let flat_range = [MultiplexerValueRange {
+            from: value,
+            to: value,
+        }];
+        if selectors.is_empty() {
+            let top_switch = message
+                .signals
+                .iter()
+                .find(|s| s.is_multiplexor() && !s.is_multiplexed());
+            if let Some(top_switch) = top_switch {
+                selectors.push((top_switch.name.as_str(), &flat_range));
+            }
+        }
+
+        let result = selectors.into_iter().any(|(switch_name, ranges)| {
+            let Some(switch_index) = message.signals.iter().position(|s| s.name == switch_name)
+            else {
+                return false;
+            };
+            if !self.is_signal_present(message, switch_index, data, present, depth + 1) {
+                return false;
+            }
+            message.signals[switch_index]
+                .decode_raw(data)
+                .is_some_and(|raw| ranges.iter().any(|range| range.contains(raw)))
+        });
+        present[index] = Some(result);
+        result
+    }
+}
+
+fn parser_messages(strict: bool) -> impl FnMut(&str) -> IResult<&str, Vec<Message>, DbcParseError> {
+    move |input| {
+        if strict {
+            parser_dbc_messages_strict(input)
+        } else {
+            many0(parser_dbc_message).parse(input)
+        }
+    }
 }
 
 pub fn dbc_value(input: &str) -> IResult<&str, NetworkAst, DbcParseError> {
+    dbc_value_with_options(input, ParseOptions::default())
+}
+
+fn dbc_value_with_options(
+    input: &str,
+    options: ParseOptions,
+) -> IResult<&str, NetworkAst, DbcParseError> {
     map(
         multispacey((
             multispacey(parser_version),
             multispacey(parser_new_symbols),
-            multispacey(parser_bit_timing),
+            multispacey(map(opt(parser_bit_timing), Option::flatten)),
             multispacey(parser_nodes),
             multispacey(parser_value_tables),
-            multispacey(many0(parser_dbc_message)),
+            // some tools write `EV_` before the messages instead of after them
+            multispacey(many0(parser_env_var)),
+            multispacey(parser_messages(options.strict)),
+            multispacey(many0(parser_message_transmitters)),
             multispacey(many0(parser_env_var)),
             multispacey(many0(parser_env_var_data)),
             multispacey(many0(parser_comment)),
             multispacey(many0(parser_attribute_definition)),
             multispacey(many0(parser_attribute_default)),
             multispacey(many0(parser_object_attribute_value)),
+            multispacey(many0(parser_signal_type_attribute_value)),
             multispacey(many0(parser_signal_value_descriptions)),
             multispacey(many0(parser_env_var_value_descriptions)),
+            multispacey(many0(parser_signal_type_value_descriptions)),
+            multispacey(many0(parser_signal_group)),
+            multispacey(many0(parser_signal_extended_value_type)),
+            multispacey(many0(parser_extended_multiplexing)),
         )),
         |(
             version,
@@ -172,43 +1339,175 @@ pub fn dbc_value(input: &str) -> IResult<&str, NetworkAst, DbcParseError> {
             bit_timing,
             nodes,
             value_tables,
+            early_env_vars,
             messages,
+            message_transmitters,
             env_vars,
             env_vars_data,
             comments,
             attribute_definitions,
             attribute_defaults,
             attribute_values,
+            signal_type_attribute_values,
             signal_value_descriptions,
             env_var_value_descriptions,
-        )| NetworkAst {
-            version,
-            new_symbols,
-            bit_timing,
-            nodes,
-            value_tables,
-            messages,
-            env_vars,
-            env_vars_data,
-            comments,
-            attribute_definitions,
-            attribute_defaults,
-            attribute_values,
-            signal_value_descriptions,
-            env_var_value_descriptions,
+            signal_type_value_descriptions,
+            signal_groups,
+            signal_extended_value_types,
+            extended_multiplexings,
+        )| {
+            let mut network = NetworkAst {
+                version,
+                new_symbols,
+                bit_timing,
+                nodes,
+                value_tables,
+                messages,
+                message_transmitters,
+                env_vars: early_env_vars.into_iter().chain(env_vars).collect(),
+                env_vars_data,
+                comments,
+                attribute_definitions,
+                attribute_defaults,
+                attribute_values,
+                signal_type_attribute_values,
+                signal_value_descriptions,
+                env_var_value_descriptions,
+                signal_type_value_descriptions,
+                signal_groups,
+                signal_extended_value_types,
+                extended_multiplexings,
+            };
+            network.resolve_attribute_defaults();
+            network
         },
     )
     .parse(input)
 }
 
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// Sort `items` by their DBC text.
+fn sort_by_text<T: fmt::Display>(items: &mut [T]) {
+    items.sort_by_cached_key(ToString::to_string);
+}
+
+/// Order of the signals of a message after [`NetworkAst::normalize`]. The multiplexer
+/// indicator and name break ties between multiplexed signals sharing a start bit.
+fn signal_order(a: &Signal, b: &Signal) -> std::cmp::Ordering {
+    let multiplexer = |signal: &Signal| {
+        signal
+            .multiplexer
+            .as_ref()
+            .map(|m| (m.multiplexer_signal, m.multiplexer_switch.is_some()))
+    };
+    a.start_bit
+        .cmp(&b.start_bit)
+        .then_with(|| multiplexer(a).cmp(&multiplexer(b)))
+        .then_with(|| a.name.cmp(&b.name))
+}
+
+/// The items written as DBC text, sorted.
+fn sorted_strings<T: fmt::Display>(items: impl IntoIterator<Item = T>) -> Vec<String> {
+    let mut strings: Vec<String> = items.into_iter().map(|item| item.to_string()).collect();
+    strings.sort();
+    strings
+}
+
+/// Parse `input` in the fixed order of the grammar or section by section, without the
+/// checks of [`ParseOptions::strict`] that need the whole network.
+fn parse_network(input: &str, options: ParseOptions) -> Result<NetworkAst, DbcParseError> {
+    if options.any_order {
+        let (network, errors) = parse_sections(input);
+        if let Some(error) = errors.into_iter().next() {
+            return Err(error.error);
+        }
+        network.into_network()
+    } else {
+        let (_remain, network) = all_consuming(|i| dbc_value_with_options(i, options))
+            .parse(input)
+            .map_err(|nom_err| {
+                log::error!("nom_err: {nom_err}");
+                match nom_err {
+                    nom::Err::Incomplete(_) => unreachable!(),
+                    nom::Err::Error(e) | nom::Err::Failure(e) => e,
+                }
+            })?;
+        Ok(network)
+    }
+}
+
+/// What a relation attribute value (`BA_REL_`) refers to, see
+/// [`NetworkAst::relation_target`].
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum RelationTarget<'a> {
+    /// `BU_EV_REL_`
+    EnvironmentVariable {
+        node: &'a str,
+        env_var: &'a EnvironmentVariable,
+    },
+    /// `BU_BO_REL_`
+    Message { node: &'a str, message: &'a Message },
+    /// `BU_SG_REL_`
+    Signal {
+        node: &'a str,
+        message: &'a Message,
+        signal: &'a Signal,
+    },
+}
+
+/// Options for [`parse_dbc_with_options`]. The default is what [`parse_dbc`] does.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct ParseOptions {
+    /// Reject defects that are otherwise only reported by [`NetworkAst::validate`], such
+    /// as duplicate message IDs, and report a malformed `SG_` or `BO_` line as
+    /// [`DbcParseError::MalformedSignal`] or [`DbcParseError::MalformedMessage`] instead of
+    /// failing on whatever section is expected after it.
+    pub strict: bool,
+    /// Accept the top-level sections in any order, e.g. `NS_` after `BU_` as some vendor
+    /// files have it, by parsing one section at a time instead of in the fixed order of
+    /// the grammar. The first section that fails to parse is the error.
+    pub any_order: bool,
+    /// Accept a last statement that lacks its terminating `;`, e.g. the final `CM_` of a
+    /// truncated capture.
+    pub missing_final_semicolon: bool,
+}
+
 pub fn parse_dbc(input: &str) -> Result<NetworkAst, DbcParseError> {
-    let (_remain, result) = all_consuming(dbc_value).parse(input).map_err(|nom_err| {
-        log::error!("nom_err: {nom_err}");
-        match nom_err {
-            nom::Err::Incomplete(_) => unreachable!(),
-            nom::Err::Error(e) | nom::Err::Failure(e) => e,
+    parse_dbc_with_options(input, ParseOptions::default())
+}
+
+pub fn parse_dbc_with_options(
+    input: &str,
+    options: ParseOptions,
+) -> Result<NetworkAst, DbcParseError> {
+    // Windows tools often start UTF-8 files with a byte order mark
+    let input = input.strip_prefix('\u{FEFF}').unwrap_or(input);
+    let result = match parse_network(input, options) {
+        Err(e) if options.missing_final_semicolon => {
+            let trimmed = input.trim_end();
+            if trimmed.ends_with(';') {
+                return Err(e);
+            }
+            // report the original error if the `;` was not what's missing
+            parse_network(&format!("{trimmed};\n"), options).map_err(|_| e)?
+        }
+        result => result?,
+    };
+
+    if options.strict {
+        if let Some(&id) = result.duplicate_message_ids().first() {
+            return Err(DbcParseError::DuplicateMessageId(id));
         }
-    })?;
+    }
     Ok(result)
 }
 
@@ -223,15 +1522,14 @@ mod tests {
     };
     use crate::ast::char_string::CharString;
     use crate::ast::env_var::EnvVarType;
-    use crate::ast::message::MessageHeader;
+    use crate::ast::message::{MessageHeader, INDEPENDENT_SIG_MSG_ID};
+    use crate::ast::nodes::VECTOR_XXX;
     use crate::ast::signal;
     use crate::ast::value_descriptions::{ValueDescriptionItem, ValueDescriptions};
+    #[cfg(feature = "bincode")]
+    use crate::error::DbcError;
 
-    #[test]
-    fn test_dbc_01() {
-        assert_eq!(
-            parse_dbc(
-                r#"VERSION "1.0"
+    const TEST_DBC_01: &str = r#"VERSION "1.0"
 
 
 NS_:
@@ -247,8 +1545,12 @@ BO_ 112 MM5_10_TX1: 8 DRS_MM5_10
  SG_ Yaw_Rate : 0|16@1+ (0.005,-163.84) [-163.84|163.83] "°/s"  ABS
  SG_ AY1 : 32|16@1+ (0.000127465,-4.1768) [-4.1768|4.1765] "g"  ABS
 
-"#
-            ),
+"#;
+
+    #[test]
+    fn test_dbc_01() {
+        assert_eq!(
+            parse_dbc(TEST_DBC_01),
             Ok(NetworkAst {
                 version: Version(CharString("1.0".into())),
                 new_symbols: NewSymbols(vec!["BS_".into(), "CM_".into()]),
@@ -273,7 +1575,7 @@ BO_ 112 MM5_10_TX1: 8 DRS_MM5_10
                             transmitter: "DRS_MM5_10".into(),
                         },
                         signals: vec![
-                            signal::Signal {
+                            Signal {
                                 name: "Yaw_Rate".into(),
                                 multiplexer: None,
                                 start_bit: 0,
@@ -287,7 +1589,7 @@ BO_ 112 MM5_10_TX1: 8 DRS_MM5_10
                                 unit: Some(CharString("°/s".into())),
                                 receivers: Some(vec!["ABS".into()]),
                             },
-                            signal::Signal {
+                            Signal {
                                 name: "AY1".into(),
                                 multiplexer: None,
                                 start_bit: 32,
@@ -304,6 +1606,7 @@ BO_ 112 MM5_10_TX1: 8 DRS_MM5_10
                         ],
                     },
                 ],
+                message_transmitters: vec![],
                 env_vars: vec![],
                 env_vars_data: vec![],
                 comments: vec![],
@@ -312,15 +1615,16 @@ BO_ 112 MM5_10_TX1: 8 DRS_MM5_10
                 attribute_values: vec![],
                 signal_value_descriptions: vec![],
                 env_var_value_descriptions: vec![],
+                signal_extended_value_types: vec![],
+                extended_multiplexings: vec![],
+                signal_type_attribute_values: vec![],
+                signal_type_value_descriptions: vec![],
+                signal_groups: vec![],
             }),
         );
     }
 
-    #[test]
-    fn test_dbc_02() {
-        assert_eq!(
-            parse_dbc(
-                r#"VERSION "1.0"
+    const TEST_DBC_02: &str = r#"VERSION "1.0"
 
 
 NS_:
@@ -361,8 +1665,12 @@ VAL_ 2147487969 Value0 2 "Value2" 1 "Value1" 0 "Value0" ;
 VAL_ RWEnvVar_wData 2 "Value2" 1 "Value1" 0 "Value0" ;
 VAL_ WriteOnlyEnvVar 2 "Value2" 1 "Value1" 0 "Value0" ;
 VAL_ ReadOnlyEnvVar 2 "Value2" 1 "Value1" 0 "Value0" ;
-"#
-            ),
+"#;
+
+    #[test]
+    fn test_dbc_02() {
+        assert_eq!(
+            parse_dbc(TEST_DBC_02),
             Ok(NetworkAst {
                 version: Version(CharString("1.0".into())),
                 new_symbols: NewSymbols(vec!["BS_".into(), "CM_".into()]),
@@ -438,7 +1746,7 @@ VAL_ ReadOnlyEnvVar 2 "Value2" 1 "Value1" 0 "Value0" ;
                             transmitter: "DRS_MM5_10".into(),
                         },
                         signals: vec![
-                            signal::Signal {
+                            Signal {
                                 name: "Yaw_Rate".into(),
                                 multiplexer: None,
                                 start_bit: 0,
@@ -452,7 +1760,7 @@ VAL_ ReadOnlyEnvVar 2 "Value2" 1 "Value1" 0 "Value0" ;
                                 unit: Some(CharString("°/s".into())),
                                 receivers: Some(vec!["ABS".into()]),
                             },
-                            signal::Signal {
+                            Signal {
                                 name: "AY1".into(),
                                 multiplexer: None,
                                 start_bit: 32,
@@ -469,6 +1777,7 @@ VAL_ ReadOnlyEnvVar 2 "Value2" 1 "Value1" 0 "Value0" ;
                         ],
                     },
                 ],
+                message_transmitters: vec![],
                 env_vars: vec![
                     EnvironmentVariable {
                         env_var_name: "UnrestrictedEnvVar".to_string(),
@@ -685,7 +1994,1578 @@ VAL_ ReadOnlyEnvVar 2 "Value2" 1 "Value1" 0 "Value0" ;
                         }
                     },
                 ],
+                signal_extended_value_types: vec![],
+                extended_multiplexings: vec![],
+                signal_type_attribute_values: vec![],
+                signal_type_value_descriptions: vec![],
+                signal_groups: vec![],
             }),
         );
     }
+
+    #[test]
+    fn test_set_message_attribute_01() {
+        let mut network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+BU_: ECU
+
+BO_ 100 Msg1: 8 ECU
+ SG_ Sig1 : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+BA_DEF_ BO_  "GenMsgCycleTime" INT 0 10000;
+BA_DEF_ BO_  "GenMsgSendType" STRING ;
+BA_DEF_ SG_  "GenSigStartValue" INT 0 255;
+BA_ "GenMsgCycleTime" BO_ 100 50;
+"#,
+        )
+        .unwrap();
+
+        network
+            .set_message_attribute(100, "GenMsgCycleTime", AttributeValue::Double(20.0))
+            .unwrap();
+        network
+            .set_message_attribute(
+                100,
+                "GenMsgSendType",
+                AttributeValue::String(CharString("Event".into())),
+            )
+            .unwrap();
+        assert_eq!(
+            network.message_attributes(100),
+            vec![
+                ("GenMsgCycleTime", &AttributeValue::Double(20.0)),
+                (
+                    "GenMsgSendType",
+                    &AttributeValue::String(CharString("Event".into()))
+                ),
+            ]
+        );
+
+        assert_eq!(
+            network.set_message_attribute(
+                100,
+                "GenMsgCycleTime",
+                AttributeValue::String(CharString("x".into()))
+            ),
+            Err(AttributeError::TypeMismatch("GenMsgCycleTime".to_string()))
+        );
+        assert_eq!(
+            network.set_message_attribute(100, "GenSigStartValue", AttributeValue::Double(1.0)),
+            Err(AttributeError::Undefined("GenSigStartValue".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_set_signal_attribute_01() {
+        let mut network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+BU_: ECU
+
+BO_ 100 Msg1: 8 ECU
+ SG_ Sig1 : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+BA_DEF_ SG_  "GenSigStartValue" INT 0 255;
+BA_ "GenSigStartValue" SG_ 100 Sig1 5;
+"#,
+        )
+        .unwrap();
+
+        network
+            .set_signal_attribute(100, "Sig1", "GenSigStartValue", AttributeValue::Double(7.0))
+            .unwrap();
+        assert_eq!(network.attribute_values.len(), 1);
+        assert_eq!(
+            network.signal_attribute_value(100, "Sig1", "GenSigStartValue"),
+            Some(&AttributeValue::Double(7.0))
+        );
+        assert_eq!(
+            network.set_signal_attribute(
+                100,
+                "Sig1",
+                "GenMsgCycleTime",
+                AttributeValue::Double(1.0)
+            ),
+            Err(AttributeError::Undefined("GenMsgCycleTime".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_message_attributes_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+BU_: ECU
+
+BO_ 100 Msg1: 8 ECU
+
+BO_ 200 Msg2: 8 ECU
+
+BA_DEF_ BO_  "GenMsgCycleTime" INT 0 10000;
+BA_DEF_ BO_  "GenMsgSendType" STRING ;
+BA_DEF_DEF_  "GenMsgCycleTime" 0;
+BA_DEF_DEF_  "GenMsgSendType" "Cyclic";
+BA_ "GenMsgCycleTime" BO_ 100 50;
+BA_ "GenMsgCycleTime" BO_ 200 20;
+BA_ "GenMsgSendType" BO_ 200 "Event";
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            network.message_attributes(100),
+            vec![("GenMsgCycleTime", &AttributeValue::Double(50.0))]
+        );
+        assert_eq!(
+            network.message_attributes(200),
+            vec![
+                ("GenMsgCycleTime", &AttributeValue::Double(20.0)),
+                (
+                    "GenMsgSendType",
+                    &AttributeValue::String(CharString("Event".into()))
+                ),
+            ]
+        );
+        assert_eq!(
+            network.message_attributes_with_defaults(100),
+            vec![
+                ("GenMsgCycleTime", &AttributeValue::Double(50.0)),
+                (
+                    "GenMsgSendType",
+                    &AttributeValue::String(CharString("Cyclic".into()))
+                ),
+            ]
+        );
+        assert!(network.message_attributes(300).is_empty());
+    }
+
+    #[test]
+    fn test_decode_signal_float_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Sensor
+
+BO_ 1024 SensorData: 8 Sensor
+ SG_ Temperature : 0|32@1- (1,0) [0|0] "degC" Vector__XXX
+ SG_ Counter : 32|32@1+ (1,0) [0|0] "" Vector__XXX
+
+SIG_VALTYPE_ 1024 Temperature : 1;
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            network.signal_extended_value_type(1024, "Temperature"),
+            SignalExtendedValueType::Float
+        );
+        assert_eq!(
+            network.signal_extended_value_type(1024, "Counter"),
+            SignalExtendedValueType::Integer
+        );
+
+        // 0xC1C80000 is -25.0_f32
+        let data = [0x00, 0x00, 0xC8, 0xC1, 0x07, 0x00, 0x00, 0x00];
+        assert_eq!(
+            network.decode_signal(1024, "Temperature", &data),
+            Some(-25.0)
+        );
+        assert_eq!(network.decode_signal(1024, "Counter", &data), Some(7.0));
+        assert_eq!(network.decode_signal(1024, "Missing", &data), None);
+        assert!(network
+            .to_string()
+            .ends_with("SIG_VALTYPE_ 1024 Temperature : 1;\n"));
+    }
+
+    #[test]
+    fn test_normalize_01() {
+        let mut a = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Gateway Engine
+
+BO_ 200 Status: 8 Gateway
+ SG_ Mode : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Flags : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+BO_ 100 Speed: 8 Engine
+ SG_ Rpm : 0|16@1+ (1,0) [0|0] "" Gateway
+
+BA_DEF_ BO_  "GenMsgSendType" STRING ;
+BA_DEF_ BO_  "GenMsgCycleTime" INT 0 65535;
+"#,
+        )
+        .unwrap();
+        let mut b = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Engine Gateway
+
+BO_ 100 Speed: 8 Engine
+ SG_ Rpm : 0|16@1+ (1,0) [0|0] "" Gateway
+
+BO_ 200 Status: 8 Gateway
+ SG_ Flags : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Mode : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+
+BA_DEF_ BO_  "GenMsgCycleTime" INT 0 65535;
+BA_DEF_ BO_  "GenMsgSendType" STRING ;
+"#,
+        )
+        .unwrap();
+        assert_ne!(a.to_string(), b.to_string());
+
+        a.normalize();
+        b.normalize();
+        assert_eq!(a.to_string(), b.to_string());
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_normalize_02() {
+        let mut a = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Mux: 8 ECU
+ SG_ Selector M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ A m1 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ B m2 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+
+CM_ SG_ 100 A "First";
+CM_ SG_ 100 B "Second";
+VAL_ 100 A 1 "On" ;
+VAL_ 100 B 1 "Off" ;
+"#,
+        )
+        .unwrap();
+        let mut b = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Mux: 8 ECU
+ SG_ B m2 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ A m1 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Selector M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+CM_ SG_ 100 B "Second";
+CM_ SG_ 100 A "First";
+VAL_ 100 B 1 "Off" ;
+VAL_ 100 A 1 "On" ;
+"#,
+        )
+        .unwrap();
+        assert_ne!(a.to_string(), b.to_string());
+
+        a.normalize();
+        b.normalize();
+        assert_eq!(a.to_string(), b.to_string());
+        assert_eq!(a, b);
+        let names: Vec<_> = a.messages[0].signals.iter().map(|s| &s.name).collect();
+        assert_eq!(names, ["Selector", "A", "B"]);
+    }
+
+    #[test]
+    fn test_to_dbc_01() {
+        let network = parse_dbc(
+            r#"VERSION "1.0"
+
+NS_:
+
+BS_:
+
+BU_: Engine
+
+BO_ 100 Speed: 8 Engine
+ SG_ Rpm : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+
+CM_ BO_ 100 "Engine speed";
+"#,
+        )
+        .unwrap();
+        assert_eq!(network.to_dbc(), format!("{network}"));
+        assert_eq!(parse_dbc(&network.to_dbc()), Ok(network));
+    }
+
+    #[test]
+    fn test_decode_log_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Engine: 2 ECU
+ SG_ Rpm : 0|16@1+ (0.25,0) [0|0] "rpm" Vector__XXX
+
+BO_ 200 Mode: 3 ECU
+ SG_ Kind M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Level m1 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Counter : 16|8@1+ (1,0) [0|0] "" Vector__XXX
+
+BO_ 300 Brake: 1 ECU
+ SG_ Counter : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+VAL_ 200 Kind 1 "Idle" ;
+"#,
+        )
+        .unwrap();
+        let frames = vec![
+            (100, vec![0x40, 0x1F]),
+            (200, vec![1, 7, 3]),
+            (300, vec![5]),
+            (200, vec![2, 9, 4]),
+            (100, vec![0x80, 0x0C]),
+            (400, vec![0]),
+        ];
+        let physical = |value: f64| Some(SignalValue::Physical(value));
+        assert_eq!(
+            network.decode_frame(200, &[1, 7, 3]),
+            Some(vec![
+                ("Kind", SignalValue::Label("Idle".to_string())),
+                ("Level", SignalValue::Physical(7.0)),
+                ("Counter", SignalValue::Physical(3.0)),
+            ])
+        );
+        let columns = network.decode_log(&frames);
+        let column = |id: u32, name: &str| &columns[&(id, name.to_string())];
+        assert_eq!(columns.len(), 5);
+        assert_eq!(
+            column(100, "Rpm"),
+            &vec![physical(2000.0), None, None, None, physical(800.0), None]
+        );
+        assert_eq!(
+            column(200, "Kind"),
+            &vec![
+                None,
+                Some(SignalValue::Label("Idle".to_string())),
+                None,
+                physical(2.0),
+                None,
+                None
+            ]
+        );
+        assert_eq!(
+            column(200, "Level"),
+            &vec![None, physical(7.0), None, None, None, None]
+        );
+        assert_eq!(
+            column(200, "Counter"),
+            &vec![None, physical(3.0), None, physical(4.0), None, None]
+        );
+        assert_eq!(
+            column(300, "Counter"),
+            &vec![None, None, physical(5.0), None, None, None]
+        );
+        assert!(network.decode_log(&[])[&(100, "Rpm".to_string())].is_empty());
+    }
+
+    #[test]
+    fn test_decode_frame_nested_multiplexing_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Tester ECU
+
+BO_ 1792 Diagnostic: 4 Tester
+ SG_ Service M : 0|8@1+ (1,0) [0|0] "" ECU
+ SG_ SubFunction m1M : 8|8@1+ (1,0) [0|0] "" ECU
+ SG_ Session m16 : 16|8@1+ (1,0) [0|0] "" ECU
+ SG_ ResetType m17 : 16|8@1+ (1,0) [0|0] "" ECU
+ SG_ Dtc m2 : 8|16@1+ (1,0) [0|0] "" ECU
+
+SG_MUL_VAL_ 1792 SubFunction Service 1-1;
+SG_MUL_VAL_ 1792 Session SubFunction 16-16;
+SG_MUL_VAL_ 1792 ResetType SubFunction 17-17, 20-25;
+"#,
+        )
+        .unwrap();
+        assert_eq!(network.extended_multiplexings.len(), 3);
+
+        assert_eq!(
+            network.decode_frame(1792, &[1, 16, 3, 0]),
+            Some(vec![
+                ("Service", SignalValue::Physical(1.0)),
+                ("SubFunction", SignalValue::Physical(16.0)),
+                ("Session", SignalValue::Physical(3.0))
+            ])
+        );
+        assert_eq!(
+            network.decode_frame(1792, &[1, 21, 2, 0]),
+            Some(vec![
+                ("Service", SignalValue::Physical(1.0)),
+                ("SubFunction", SignalValue::Physical(21.0)),
+                ("ResetType", SignalValue::Physical(2.0))
+            ])
+        );
+        assert_eq!(
+            network.decode_frame(1792, &[2, 0x34, 0x12, 0]),
+            Some(vec![
+                ("Service", SignalValue::Physical(2.0)),
+                ("Dtc", SignalValue::Physical(4660.0))
+            ])
+        );
+        assert_eq!(
+            network.decode_frame(1792, &[3, 16, 3, 0]),
+            Some(vec![("Service", SignalValue::Physical(3.0))])
+        );
+        assert_eq!(network.decode_frame(1793, &[0; 4]), None);
+    }
+
+    #[test]
+    fn test_parse_dbc_duplicate_message_id_01() {
+        let input = r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+
+BO_ 100 Second: 8 ECU
+"#;
+        let network = parse_dbc(input).unwrap();
+        assert_eq!(network.messages.len(), 2);
+        assert_eq!(network.message_by_id(100).unwrap().header.name, "First");
+        assert!(!network.validate().is_empty());
+
+        assert_eq!(
+            parse_dbc_with_options(
+                input,
+                ParseOptions {
+                    strict: true,
+                    ..ParseOptions::default()
+                }
+            ),
+            Err(DbcParseError::DuplicateMessageId(100))
+        );
+    }
+
+    #[test]
+    fn test_messages_from_01() {
+        let network = parse_dbc(include_str!("../../dbc/mytest/abs.dbc")).unwrap();
+        let ids: Vec<u32> = network
+            .messages_from("ABS")
+            .iter()
+            .map(|m| m.header.id)
+            .collect();
+        assert!(ids.contains(&117));
+        assert!(!ids.contains(&112));
+        assert!(network.messages_from("NoSuchNode").is_empty());
+    }
+
+    #[test]
+    fn test_messages_from_02() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ABS Gateway
+
+BO_ 117 DRS_RX_ID0: 8 ABS
+
+BO_ 118 Shared: 8 ABS
+
+BO_TX_BU_ 118 : ABS,Gateway;
+"#,
+        )
+        .unwrap();
+        let ids: Vec<u32> = network
+            .messages_from("Gateway")
+            .iter()
+            .map(|m| m.header.id)
+            .collect();
+        assert_eq!(ids, vec![118]);
+        assert_eq!(network.messages_from("ABS").len(), 2);
+        assert!(network
+            .to_string()
+            .contains("BO_TX_BU_ 118 : ABS,Gateway;\n"));
+    }
+
+    #[test]
+    fn test_subset_for_node_01() {
+        let network = parse_dbc(
+            r#"VERSION "1.0"
+
+NS_:
+    CM_
+    BA_DEF_
+    BA_
+
+BS_:
+
+BU_: Engine Gateway Dashboard Brakes
+
+BO_ 100 EngineSpeed: 8 Engine
+ SG_ Rpm : 0|16@1+ (1,0) [0|8000] "rpm" Dashboard
+
+BO_ 200 WheelSpeed: 8 Brakes
+ SG_ Speed : 0|16@1+ (0.01,0) [0|300] "km/h" Gateway
+
+BO_ 300 GatewayStatus: 8 Gateway
+ SG_ Status : 0|8@1+ (1,0) [0|0] "" Engine
+
+CM_ BU_ Brakes "Brake controller";
+CM_ BO_ 100 "Engine speed";
+CM_ BO_ 200 "Wheel speed";
+BA_DEF_ BO_  "GenMsgCycleTime" INT 0 65535;
+BA_ "GenMsgCycleTime" BO_ 100 10;
+BA_ "GenMsgCycleTime" BO_ 200 20;
+"#,
+        )
+        .unwrap();
+        let subset = network.subset_for_node("Engine");
+        let ids: Vec<u32> = subset.messages.iter().map(|m| m.header.id).collect();
+        assert_eq!(ids, vec![100, 300]);
+        assert_eq!(
+            subset.nodes,
+            Nodes(vec!["Engine".into(), "Gateway".into(), "Dashboard".into()])
+        );
+        assert_eq!(
+            subset
+                .comments
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![r#"CM_ BO_ 100 "Engine speed";"#]
+        );
+        assert_eq!(subset.attribute_definitions, network.attribute_definitions);
+        assert_eq!(subset.message_attributes(100).len(), 1);
+        assert!(subset.message_attributes(200).is_empty());
+        assert_eq!(subset.version, network.version);
+    }
+
+    #[test]
+    fn test_find_signals_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+ SG_ Counter : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+
+BO_ 200 Second: 8 ECU
+ SG_ Value : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Counter : 8|4@1+ (1,0) [0|15] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let found: Vec<(u32, u32)> = network
+            .find_signals("Counter")
+            .into_iter()
+            .map(|(message, signal)| (message.header.id, signal.start_bit))
+            .collect();
+        assert_eq!(found, vec![(100, 0), (200, 8)]);
+        assert!(network.find_signals("Missing").is_empty());
+    }
+
+    #[test]
+    fn test_format_message_block_01() {
+        let input = std::fs::read_to_string("dbc/mytest/abs.dbc").unwrap();
+        let network = parse_dbc(&input).unwrap();
+        assert_eq!(
+            network.format_message_block(112).unwrap(),
+            r#"BO_ 112 MM5_10_TX1: 8 DRS_MM5_10
+	SG_ Yaw_Rate : 0|16@1+ (0.005,-163.84) [-163.84|163.83] "°/s" ABS
+	SG_ AY1 : 32|16@1+ (0.000127465,-4.1768) [-4.1768|4.1765] "g" ABS
+
+CM_ SG_ 112 Yaw_Rate "Measured yaw rate around the Z axle.";
+CM_ SG_ 112 AY1 "Measured lateral acceleration.";
+
+BA_ "VFrameFormat" BO_ 112 0;
+"#
+        );
+        assert!(network.format_message_block(1).is_none());
+    }
+
+    #[test]
+    fn test_messages_containing_signal_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 300 Muxed: 8 ECU
+ SG_ Mux M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Counter m1 : 8|4@1+ (1,0) [0|15] "" Vector__XXX
+ SG_ Counter m2 : 12|4@1+ (1,0) [0|15] "" Vector__XXX
+
+BO_ 100 First: 8 ECU
+ SG_ Counter : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+
+BO_ 200 Second: 8 ECU
+ SG_ Value : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            network.messages_containing_signal("Counter"),
+            vec![300, 100]
+        );
+        assert_eq!(network.messages_containing_signal("Value"), vec![200]);
+        assert!(network.messages_containing_signal("Missing").is_empty());
+    }
+
+    #[test]
+    fn test_parse_dbc_keyword_identifier_01() {
+        let header = "VERSION \"\"\n\nNS_:\n\nBS_:\n\n";
+        for body in [
+            "BU_: ECU\n\nBO_ 100 BS_: 8 ECU\n",
+            "BU_: ECU\n\nBO_ 100 Status: 8 ECU\n SG_ CM_ : 0|8@1+ (1,0) [0|0] \"\" ECU\n",
+            "BU_: ECU CM_\n\nBO_ 100 Status: 8 ECU\n",
+        ] {
+            let input = format!("{header}{body}");
+            assert_eq!(
+                parse_dbc(&input),
+                Err(DbcParseError::UseKeywordAsIdentifier),
+                "{body}"
+            );
+            let options = ParseOptions {
+                any_order: true,
+                ..ParseOptions::default()
+            };
+            assert_eq!(
+                parse_dbc_with_options(&input, options),
+                Err(DbcParseError::UseKeywordAsIdentifier),
+                "{body}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_dbc_strict_malformed_signal_01() {
+        let input = r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Engine: 8 ECU
+ SG_ Rpm : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+ SG_ Temperature : 16|8@1 (1,-40) [0|0] "degC" Vector__XXX
+
+CM_ BO_ 100 "Engine";
+"#;
+        assert!(parse_dbc(input).is_err());
+        assert_eq!(
+            parse_dbc_with_options(
+                input,
+                ParseOptions {
+                    strict: true,
+                    ..ParseOptions::default()
+                }
+            ),
+            Err(DbcParseError::MalformedSignal(
+                r#"SG_ Temperature : 16|8@1 (1,-40) [0|0] "degC" Vector__XXX"#.into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_signal_start_value_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Engine: 8 ECU
+ SG_ Temperature : 0|8@1+ (0.5,-40) [-40|87.5] "degC" Vector__XXX
+ SG_ Load : 8|8@1+ (1,0) [0|100] "%" Vector__XXX
+
+BA_DEF_ SG_  "GenSigStartValue" INT 0 65535;
+BA_DEF_DEF_  "GenSigStartValue" 0;
+BA_ "GenSigStartValue" SG_ 100 Temperature 100;
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            network.signal_start_value_raw(100, "Temperature"),
+            Some(100.0)
+        );
+        assert_eq!(network.signal_start_value(100, "Temperature"), Some(10.0));
+        assert_eq!(network.signal_start_value_raw(100, "Load"), Some(0.0));
+        assert_eq!(network.signal_start_value(100, "Load"), Some(0.0));
+        assert_eq!(network.signal_start_value(100, "Missing"), None);
+    }
+
+    #[test]
+    fn test_env_var_data_comment_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Node2
+
+EV_ RWEnvVar_wData: 0 [0|1234] "" 60 2 DUMMY_NODE_VECTOR3  Node2;
+
+ENVVAR_DATA_ RWEnvVar_wData: 10;
+
+CM_ EV_ RWEnvVar_wData "Data buffer of 10 bytes";
+BA_DEF_ EV_  "RWEnvVar_wData_Val" INT 0 10;
+BA_ "RWEnvVar_wData_Val" EV_ RWEnvVar_wData 3;
+"#,
+        )
+        .unwrap();
+        assert_eq!(network.env_vars_data.len(), 1);
+        assert_eq!(
+            network.comments[0].to_string(),
+            r#"CM_ EV_ RWEnvVar_wData "Data buffer of 10 bytes";"#
+        );
+        assert_eq!(network.attribute_values.len(), 1);
+    }
+
+    #[test]
+    fn test_value_table_map_01() {
+        let network = parse_dbc(TEST_DBC_02).unwrap();
+        let map = network.value_table_map();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["ABS_fault_info"].values.len(), 3);
+        assert_eq!(map["vt_WheelSpeedQualifier"].values.len(), 6);
+        assert_eq!(
+            map["vt_WheelSpeedQualifier"].values[0].str.0,
+            "InvalidUnderVoltage"
+        );
+
+        let network = NetworkAst {
+            value_tables: None,
+            ..network
+        };
+        assert!(network.value_table_map().is_empty());
+    }
+
+    #[test]
+    fn test_to_dbc_without_bit_timing_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+"#,
+        )
+        .unwrap();
+        assert!(network.to_dbc().contains("BS_:"));
+
+        let dbc = network.to_dbc_with_options(FormatOptions {
+            emit_bit_timing: false,
+            ..FormatOptions::default()
+        });
+        assert!(!dbc.contains("BS_"));
+        let reparsed = parse_dbc(&dbc).unwrap();
+        assert_eq!(reparsed.bit_timing, None);
+        assert_eq!(reparsed.messages, network.messages);
+    }
+
+    #[test]
+    fn test_message_ids_01() {
+        let network = parse_dbc(TEST_DBC_01).unwrap();
+        assert_eq!(network.message_ids(), vec![112, 117]);
+        assert_eq!(network.can_ids(), vec![112, 117]);
+
+        let network = parse_dbc(&format!(
+            "{TEST_DBC_01}BO_ 2364540158 EEC1: 8 ABS\n\n\
+             BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG: 0 Vector__XXX\n"
+        ))
+        .unwrap();
+        assert_eq!(
+            network.message_ids(),
+            vec![112, 117, 2_364_540_158, INDEPENDENT_SIG_MSG_ID]
+        );
+        assert_eq!(network.can_ids(), vec![112, 117, 0x0CF0_04FE]);
+    }
+
+    #[test]
+    fn test_vector_xxx_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU Display
+
+BO_ 100 Status: 8 ECU
+ SG_ Speed : 0|16@1+ (1,0) [0|0] "" Display,Vector__XXX
+ SG_ Spare : 16|8@1+ (1,0) [0|0] "" Vector__XXX
+
+BO_ 200 Unsent: 8 Vector__XXX
+ SG_ Debug : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let speed = &network.messages[0].signals[0];
+        assert_eq!(speed.receiver_count(), 1);
+        assert_eq!(network.messages[0].signals[1].receiver_count(), 0);
+
+        let received: Vec<&str> = network
+            .signals_received_by("Display")
+            .iter()
+            .map(|(_, s)| s.name.as_str())
+            .collect();
+        assert_eq!(received, vec!["Speed"]);
+        assert!(is_vector_xxx(&network.messages[1].header.transmitter));
+        assert!(!is_vector_xxx("ECU"));
+        assert!(network.signals_received_by(VECTOR_XXX).is_empty());
+        assert!(network.messages_from(VECTOR_XXX).is_empty());
+        assert!(network.subset_for_node(VECTOR_XXX).messages.is_empty());
+        assert!(!network.messages[1].header.has_sender());
+    }
+
+    #[test]
+    fn test_parse_dbc_any_order_01() {
+        let input = r#"VERSION ""
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+ SG_ Speed : 0|16@1+ (1,0) [0|0] "" Vector__XXX
+
+NS_:
+    CM_
+
+CM_ BO_ 100 "First message";
+
+BS_:
+"#;
+        assert!(parse_dbc(input).is_err());
+        let any_order = ParseOptions {
+            any_order: true,
+            ..ParseOptions::default()
+        };
+        let network = parse_dbc_with_options(input, any_order).unwrap();
+        assert!(network.declares_symbol("CM_"));
+        assert_eq!(network.nodes, Nodes(vec!["ECU".to_string()]));
+        assert_eq!(network.messages[0].header.name, "First");
+        assert_eq!(network.comments.len(), 1);
+        assert_eq!(
+            parse_dbc_with_options(&network.to_string(), ParseOptions::default()),
+            Ok(network)
+        );
+
+        assert_eq!(
+            parse_dbc_with_options("BU_: ECU\nVERSION \"\"\n", any_order),
+            Err(DbcParseError::BadNames)
+        );
+    }
+
+    #[test]
+    fn test_parse_dbc_env_var_before_messages_01() {
+        let input = r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+EV_ Early: 0 [0|100] "" 0 1 DUMMY_NODE_VECTOR0 ECU;
+
+BO_ 100 First: 8 ECU
+ SG_ Speed : 0|16@1+ (1,0) [0|0] "" Vector__XXX
+
+EV_ Late: 0 [0|100] "" 0 2 DUMMY_NODE_VECTOR0 ECU;
+"#;
+        let network = parse_dbc(input).unwrap();
+        let names: Vec<&str> = network
+            .env_vars
+            .iter()
+            .map(|e| e.env_var_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Early", "Late"]);
+        assert_eq!(network.messages.len(), 1);
+        let any_order = ParseOptions {
+            any_order: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            parse_dbc_with_options(input, any_order),
+            Ok(network.clone())
+        );
+        assert_eq!(parse_dbc(&network.to_string()), Ok(network));
+    }
+
+    #[test]
+    fn test_relation_target_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+    BA_DEF_
+    BA_REL_
+
+BS_:
+
+BU_: Gateway ECU
+
+BO_ 100 First: 8 ECU
+ SG_ Speed : 0|16@1+ (1,0) [0|0] "" Gateway
+
+EV_ Mode: 0 [0|3] "" 0 1 DUMMY_NODE_VECTOR0 Gateway;
+
+BA_DEF_REL_ BU_EV_REL_  "EnvAttr" STRING ;
+BA_DEF_REL_ BU_BO_REL_  "TxAttr" INT 0 100;
+BA_DEF_REL_ BU_SG_REL_  "RxAttr" INT 0 100;
+
+BA_REL_ "EnvAttr" BU_EV_REL_ Gateway Mode "Value";
+BA_REL_ "TxAttr" BU_BO_REL_ ECU 100 10;
+BA_REL_ "RxAttr" BU_SG_REL_ Gateway SG_ 100 Speed 20;
+BA_REL_ "RxAttr" BU_SG_REL_ Gateway SG_ 100 Missing 30;
+"#,
+        )
+        .unwrap();
+        assert!(network.validate().is_empty());
+        let values = &network.attribute_values;
+        assert_eq!(values.len(), 4);
+        assert_eq!(
+            network.relation_target(&values[0]),
+            Some(RelationTarget::EnvironmentVariable {
+                node: "Gateway",
+                env_var: &network.env_vars[0],
+            })
+        );
+        assert_eq!(
+            network.relation_target(&values[1]),
+            Some(RelationTarget::Message {
+                node: "ECU",
+                message: &network.messages[0],
+            })
+        );
+        assert_eq!(
+            network.relation_target(&values[2]),
+            Some(RelationTarget::Signal {
+                node: "Gateway",
+                message: &network.messages[0],
+                signal: &network.messages[0].signals[0],
+            })
+        );
+        assert_eq!(network.relation_target(&values[3]), None);
+        assert_eq!(parse_dbc(&network.to_string()), Ok(network.clone()));
+    }
+
+    #[test]
+    fn test_undefined_attributes_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+
+BA_DEF_ BO_  "GenMsgCycleTime" INT 0 10000;
+BA_DEF_DEF_  "GenMsgCycleTime" 0;
+BA_DEF_DEF_  "GenMsgDelayTime" 0;
+BA_ "GenMsgCycleTime" BO_ 100 10;
+BA_ "GenMsgSendType" BO_ 100 0;
+BA_ "GenMsgDelayTime" BO_ 100 5;
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            network.undefined_attributes(),
+            vec!["GenMsgSendType".to_string(), "GenMsgDelayTime".to_string()]
+        );
+        assert!(parse_dbc(TEST_DBC_02)
+            .unwrap()
+            .undefined_attributes()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_attribute_definitions_for_01() {
+        let network = parse_dbc(TEST_DBC_02).unwrap();
+        let names = |kind| -> Vec<&str> {
+            network
+                .attribute_definitions_for(kind)
+                .into_iter()
+                .map(AttributeDefinition::attribute_name)
+                .collect()
+        };
+        assert_eq!(names(AttributeObjectKind::Signal), vec!["SGEnumAttribute"]);
+        assert_eq!(
+            names(AttributeObjectKind::EnvironmentVariable),
+            vec!["RWEnvVar_wData_Val", "GlobalEnvVar_Val"]
+        );
+        assert_eq!(names(AttributeObjectKind::Network), vec!["FloatAttribute"]);
+        assert!(names(AttributeObjectKind::NodeTxMessage).is_empty());
+    }
+
+    #[test]
+    fn test_parse_dbc_missing_final_semicolon_01() {
+        let input = r#"VERSION ""
+
+NS_:
+    CM_
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+
+CM_ BO_ 100 "Truncated capture"
+"#;
+        assert!(parse_dbc(input).is_err());
+        let lenient = ParseOptions {
+            missing_final_semicolon: true,
+            ..ParseOptions::default()
+        };
+        let network = parse_dbc_with_options(input, lenient).unwrap();
+        assert_eq!(network.comments.len(), 1);
+        assert_eq!(parse_dbc(&network.to_string()), Ok(network));
+
+        let broken = "VERSION \"\"\n\nNS_:\n\nBS_:\n\nBU_: ECU\n\nCM_ BO_ x \"Bad\"";
+        assert_eq!(parse_dbc_with_options(broken, lenient), parse_dbc(broken));
+    }
+
+    #[test]
+    fn test_to_dbc_line_ending_01() {
+        let network = parse_dbc(TEST_DBC_02).unwrap();
+        let dbc = network.to_dbc_with_options(FormatOptions {
+            line_ending: LineEnding::CrLf,
+            ..FormatOptions::default()
+        });
+        assert_eq!(dbc.matches('\n').count(), dbc.matches("\r\n").count());
+        assert_eq!(dbc.replace("\r\n", "\n"), network.to_dbc());
+        assert_eq!(parse_dbc(&dbc), Ok(network));
+    }
+
+    #[test]
+    fn test_parse_dbc_utf8_bom_01() {
+        let input = std::fs::read_to_string("dbc/mytest/utf8_bom.dbc").unwrap();
+        assert!(input.starts_with('\u{FEFF}'));
+        let network = parse_dbc(&input).unwrap();
+        assert_eq!(network.version, Version(CharString("1.0".into())));
+        assert_eq!(network.messages[0].header.name, "Status");
+        let any_order = ParseOptions {
+            any_order: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(parse_dbc_with_options(&input, any_order), Ok(network));
+    }
+
+    #[test]
+    fn test_fill_missing_ranges_01() {
+        let mut network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+ SG_ Temperature : 0|8@1+ (0.5,-40) "degC" ECU
+ SG_ Delta : 8|4@1- (1,0) [0|0] "" ECU
+ SG_ Speed : 16|16@1+ (0.1,0) [0|250] "km/h" ECU
+"#,
+        )
+        .unwrap();
+        network.fill_missing_ranges();
+        let ranges: Vec<(Option<f64>, Option<f64>)> = network.messages[0]
+            .signals
+            .iter()
+            .map(|s| (s.min, s.max))
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (Some(-40.0), Some(87.5)),
+                (Some(-8.0), Some(7.0)),
+                (Some(0.0), Some(250.0)),
+            ]
+        );
+        assert!(network
+            .to_string()
+            .contains(r#"SG_ Temperature : 0|8@1+ (0.5,-40) [-40|87.5] "degC" ECU"#));
+    }
+
+    #[test]
+    fn test_resolve_attribute_defaults_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BA_DEF_ BO_ "GenMsgDelay" FLOAT 0 100.5;
+BA_DEF_ BO_ "GenMsgLabel" STRING;
+BA_DEF_ BO_ "GenMsgCycleTime" INT 0 65535;
+BA_DEF_DEF_ "GenMsgDelay" "2.5";
+BA_DEF_DEF_ "GenMsgLabel" "5";
+BA_DEF_DEF_ "GenMsgCycleTime" "fast";
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            network.attribute_default("GenMsgDelay"),
+            Some(&AttributeValue::Double(2.5))
+        );
+        assert_eq!(
+            network.attribute_default("GenMsgLabel"),
+            Some(&AttributeValue::String(CharString("5".into())))
+        );
+        assert_eq!(
+            network.attribute_default("GenMsgCycleTime"),
+            Some(&AttributeValue::String(CharString("fast".into())))
+        );
+        assert!(network
+            .to_string()
+            .contains(r#"BA_DEF_DEF_ "GenMsgDelay" 2.5;"#));
+    }
+
+    #[test]
+    fn test_rename_signal_01() {
+        let mut network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+ SG_ Speed : 0|16@1+ (0.1,0) [0|250] "km/h" ECU
+ SG_ Gear : 16|8@1+ (1,0) [0|0] "" ECU
+
+BO_ 200 Second: 8 ECU
+ SG_ Speed : 0|16@1+ (0.1,0) [0|250] "km/h" ECU
+
+CM_ SG_ 100 Speed "Vehicle speed";
+CM_ SG_ 200 Speed "Other speed";
+BA_DEF_ SG_ "GenSigStartValue" INT 0 65535;
+BA_ "GenSigStartValue" SG_ 100 Speed 10;
+VAL_ 100 Speed 0 "Stopped" ;
+SIG_GROUP_ 100 Motion 1 : Speed Gear;
+"#,
+        )
+        .unwrap();
+        assert!(!network.rename_signal(100, "Missing", "Other"));
+        assert!(!network.rename_signal(300, "Speed", "Other"));
+        let unchanged = network.clone();
+        assert!(!network.rename_signal(100, "Speed", "Gear"));
+        assert_eq!(network, unchanged);
+
+        assert!(network.rename_signal(100, "Speed", "VehicleSpeed"));
+        assert_eq!(network.messages[0].signals[0].name, "VehicleSpeed");
+        assert_eq!(network.messages[1].signals[0].name, "Speed");
+        assert_eq!(
+            network.signal_value_descriptions[0].signal_name,
+            "VehicleSpeed"
+        );
+        assert_eq!(
+            network.signal_groups[0].signal_names,
+            vec!["VehicleSpeed".to_string(), "Gear".to_string()]
+        );
+
+        let text = network.to_string();
+        for line in [
+            r#"SG_ VehicleSpeed : 0|16@1+ (0.1,0) [0|250] "km/h" ECU"#,
+            r#"CM_ SG_ 100 VehicleSpeed "Vehicle speed";"#,
+            r#"CM_ SG_ 200 Speed "Other speed";"#,
+            r#"BA_ "GenSigStartValue" SG_ 100 VehicleSpeed 10;"#,
+            r#"VAL_ 100 VehicleSpeed 0 "Stopped";"#,
+            "SIG_GROUP_ 100 Motion 1 : VehicleSpeed Gear;",
+        ] {
+            assert!(text.contains(line), "missing {line:?} in\n{text}");
+        }
+        assert!(!text.contains(" 100 Speed "));
+    }
+
+    #[test]
+    fn test_semantic_hash_01() {
+        let a = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+    CM_
+
+BS_:
+
+BU_: ECU Gateway
+
+BO_ 100 First: 8 ECU
+ SG_ Speed : 0|16@1+ (0.1,0) [0|0] "km/h" Vector__XXX
+ SG_ Gear : 16|4@1+ (1,0) [0|0] "" Vector__XXX
+
+BO_ 200 Second: 8 Gateway
+
+CM_ BO_ 100 "First message";
+CM_ BO_ 200 "Second message";
+"#,
+        )
+        .unwrap();
+        let b = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+    CM_
+
+BS_:
+
+BU_: Gateway ECU
+
+BO_ 200  Second :  8 Gateway
+
+BO_ 100 First: 8 ECU
+ SG_ Gear : 16|4@1+ (1.0,0.0) [0|0] "" Vector__XXX
+ SG_ Speed : 0|16@1+ (0.10,0) [0|0] "km/h" Vector__XXX
+
+CM_ BO_ 200 "Second message";
+CM_ BO_ 100 "First message";
+"#,
+        )
+        .unwrap();
+        assert_ne!(a, b);
+        assert_eq!(a.semantic_hash(), b.semantic_hash());
+
+        let mut c = b.clone();
+        c.messages[1].signals[1].factor = 0.2;
+        assert_ne!(a.semantic_hash(), c.semantic_hash());
+    }
+
+    #[test]
+    fn test_semantic_hash_02() {
+        let a = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Mux: 8 ECU
+ SG_ Selector M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ A m1 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ B m2 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let mut b = a.clone();
+        b.messages[0].signals.swap(1, 2);
+        assert_ne!(a, b);
+        assert_eq!(a.semantic_hash(), b.semantic_hash());
+
+        b.messages[0].signals[0].multiplexer = None;
+        assert_ne!(a.semantic_hash(), b.semantic_hash());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_to_bytes_01() {
+        let network = parse_dbc(TEST_DBC_02).unwrap();
+        let bytes = network.to_bytes();
+        assert_eq!(NetworkAst::from_bytes(&bytes), Ok(network));
+        assert_eq!(
+            NetworkAst::from_bytes(&bytes[..bytes.len() / 2]),
+            Err(DbcError::InvalidBytes)
+        );
+    }
+
+    #[test]
+    fn test_send_type_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Cyclic: 8 ECU
+
+BO_ 200 Event: 8 ECU
+
+BO_ 300 Mixed: 8 ECU
+
+BO_ 400 CycleTimeOnly: 8 ECU
+
+BA_DEF_ BO_  "GenMsgSendType" ENUM  "Cyclic","Spontaneous","CyclicAndSpontaneous","NotUsed";
+BA_DEF_ BO_  "GenMsgCycleTime" INT 0 10000;
+BA_DEF_DEF_  "GenMsgCycleTime" 0;
+BA_ "GenMsgSendType" BO_ 100 0;
+BA_ "GenMsgCycleTime" BO_ 100 10;
+BA_ "GenMsgSendType" BO_ 200 1;
+BA_ "GenMsgSendType" BO_ 300 2;
+BA_ "GenMsgCycleTime" BO_ 400 100;
+"#,
+        )
+        .unwrap();
+        assert_eq!(network.send_type(100), SendType::Cyclic);
+        assert_eq!(network.send_type(200), SendType::Event);
+        assert_eq!(network.send_type(300), SendType::CyclicAndEvent);
+        assert_eq!(network.send_type(400), SendType::Cyclic);
+        assert_eq!(network.send_type(500), SendType::Unknown);
+    }
+
+    #[test]
+    fn test_dbc_skeleton_01() {
+        let network = parse_dbc("VERSION \"\"\n\n\nNS_ :\n\nBS_:\n\nBU_:\n\n\n").unwrap();
+        assert_eq!(network.version, Version(CharString(String::new())));
+        assert_eq!(network.nodes, Nodes(vec![]));
+        assert!(network.messages.is_empty());
+        assert!(network.comments.is_empty());
+        assert_eq!(parse_dbc(&network.to_string()), Ok(network));
+    }
+
+    #[test]
+    fn test_signal_groups_01() {
+        let input = r#"VERSION ""
+
+NS_:
+    VAL_
+    SIG_GROUP_
+
+BS_:
+
+BU_: ECU
+
+BO_ 256 Protected: 8 ECU
+ SG_ Counter : 0|4@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Checksum : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Speed : 16|16@1+ (1,0) [0|0] "" Vector__XXX
+
+BO_ 512 Other: 8 ECU
+
+VAL_ 256 Counter 0 "Zero" ;
+
+SIG_GROUP_ 256 E2E_Group 3 : Counter Checksum Speed;
+SIG_GROUP_ 256 Speed_Group 1 : Speed;
+"#;
+        let network = parse_dbc(input).unwrap();
+        let groups = network.signal_groups(256);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].repetitions, 3);
+        assert_eq!(groups[0].signal_names, vec!["Counter", "Checksum", "Speed"]);
+        assert!(network.signal_groups(512).is_empty());
+        assert_eq!(network.validate(), vec![]);
+
+        let formatted = network.to_string();
+        assert!(formatted.contains("SIG_GROUP_ 256 E2E_Group 3 : Counter Checksum Speed;\n"));
+        assert_eq!(parse_dbc(&formatted), Ok(network));
+    }
+
+    #[test]
+    fn test_units_01() {
+        let units = parse_dbc(TEST_DBC_01).unwrap().units();
+        assert_eq!(units, BTreeSet::from(["g".to_string(), "°/s".to_string()]));
+    }
+
+    #[test]
+    fn test_message_of_signal_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+ SG_ Speed : 0|16@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Checksum : 56|8@1+ (1,0) [0|0] "" Vector__XXX
+
+BO_ 200 Second: 8 ECU
+ SG_ Checksum : 56|8@1+ (1,0) [0|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let names = |sig_name| -> Vec<&str> {
+            network
+                .message_of_signal(sig_name)
+                .iter()
+                .map(|m| m.header.name.as_str())
+                .collect()
+        };
+        assert_eq!(names("Speed"), vec!["First"]);
+        assert_eq!(names("Checksum"), vec!["First", "Second"]);
+        assert!(names("Missing").is_empty());
+    }
+
+    #[test]
+    fn test_signal_type_sections_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+    SGTYPE_VAL_
+    BA_SGTYPE_
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Status: 8 ECU
+ SG_ Speed : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+BA_SGTYPE_ "SGTypeAttr" SGTYPE_ SpeedType 5;
+
+VAL_ 100 Speed 0 "Stop" ;
+SGTYPE_VAL_ SpeedType 0 "Stop" 1 "Move" ;
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            network.signal_type_attribute_values[0].to_string(),
+            r#"BA_SGTYPE_ "SGTypeAttr" SGTYPE_ SpeedType 5;"#
+        );
+        assert_eq!(
+            network.signal_type_value_descriptions[0].to_string(),
+            r#"SGTYPE_VAL_ SpeedType 0 "Stop" 1 "Move";"#
+        );
+        assert_eq!(parse_dbc(&network.to_dbc()).unwrap(), network);
+    }
+
+    #[test]
+    fn test_to_dbc_fixed_point_01() {
+        let network = parse_dbc(
+            r#"VERSION   "1.0"
+
+NS_ :
+    CM_
+
+BS_:
+
+BU_:   Node   Gateway
+
+BO_ 1  Foo :  8  Node
+ SG_  Speed :  0|16@1+  (0.1,0)  [0|6553.5]  "km/h"   Gateway
+BO_TX_BU_  1 :  Node ,Gateway ;
+
+CM_  BO_ 1  "Foo message" ;
+"#,
+        )
+        .unwrap();
+        let formatted = network.to_dbc();
+        assert!(formatted.contains("BO_ 1 Foo: 8 Node\n"));
+        let reformatted = parse_dbc(&formatted).unwrap().to_dbc();
+        assert_eq!(reformatted, formatted);
+
+        let input = std::fs::read_to_string("dbc/mytest/abs.dbc").unwrap();
+        let formatted = parse_dbc(&input).unwrap().to_dbc();
+        assert_eq!(parse_dbc(&formatted).unwrap().to_dbc(), formatted);
+    }
+
+    #[test]
+    fn test_to_dbc_fixed_point_02() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Node Gateway
+
+BO_ 1 Foo: 8 Node
+ SG_ Speed : 0|16@1+ (0.1,0) [0|6553.5] "km/h" Gateway
+
+EV_ Env: 0 [0|10] "" 0 1 DUMMY_NODE_VECTOR0 Node;
+
+BA_DEF_  "BusType" STRING ;
+BA_DEF_ BU_  "NodeLayer"  INT  0  255;
+BA_DEF_  BO_ "GenMsgCycleTime" INT 0 65535 ;
+BA_DEF_ BO_  "GenMsgSendType"  ENUM  "Cyclic","Event" ,"IfActive";
+BA_DEF_ SG_  "GenSigStartValue" FLOAT  -3.5  1e3;
+BA_DEF_ EV_  "EnvMask" HEX 0  255;
+BA_DEF_REL_ BU_SG_REL_  "GenSigTimeout"  INT 0 1000;
+BA_DEF_DEF_  "BusType" "CAN";
+BA_DEF_DEF_  "NodeLayer" 0;
+BA_DEF_DEF_ "GenMsgCycleTime"  100 ;
+BA_DEF_DEF_ "GenMsgSendType" "Cyclic";
+BA_DEF_DEF_ "GenSigStartValue"  "0.5";
+BA_DEF_DEF_ "EnvMask" 0;
+BA_DEF_DEF_REL_  "GenSigTimeout" 0;
+BA_ "BusType"  "CAN";
+BA_ "GenMsgCycleTime" BO_ 1  20;
+BA_REL_ "GenSigTimeout" BU_SG_REL_ Gateway SG_ 1 Speed 50;
+"#,
+        )
+        .unwrap();
+        let formatted = network.to_dbc();
+        assert!(formatted.contains(r#"BA_DEF_ "BusType" STRING;"#));
+        assert!(formatted.contains(r#"BA_DEF_ BU_ "NodeLayer" INT 0 255;"#));
+        assert_eq!(parse_dbc(&formatted).unwrap().to_dbc(), formatted);
+
+        let input = std::fs::read_to_string("dbc/mytest/DBC_template.dbc").unwrap();
+        let formatted = parse_dbc(&input).unwrap().to_dbc();
+        assert_eq!(parse_dbc(&formatted).unwrap().to_dbc(), formatted);
+    }
 }
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use nom::bytes::complete::tag;
-use nom::character::complete::{line_ending, space0};
+use nom::character::complete::{line_ending, multispace0, space0};
 use nom::combinator::map;
 use nom::multi::many0;
 use nom::{IResult, Parser};
@@ -19,7 +19,9 @@ use super::error::DbcParseError;
 ///     CM_
 ///     ...
 /// ```
-/// */
+///
+/// Blank lines between symbols are accepted, but not kept: [`fmt::Display`] emits one
+/// tab-indented symbol per line.
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NewSymbols(pub Vec<String>);
@@ -34,9 +36,54 @@ impl fmt::Display for NewSymbols {
     }
 }
 
+/// The `NS_` symbols Vector tools write, in their order.
+const STANDARD_SYMBOLS: [&str; 28] = [
+    "NS_DESC_",
+    "CM_",
+    "BA_DEF_",
+    "BA_",
+    "VAL_",
+    "CAT_DEF_",
+    "CAT_",
+    "FILTER",
+    "BA_DEF_DEF_",
+    "EV_DATA_",
+    "ENVVAR_DATA_",
+    "SGTYPE_",
+    "SGTYPE_VAL_",
+    "BA_DEF_SGTYPE_",
+    "BA_SGTYPE_",
+    "SIG_TYPE_REF_",
+    "VAL_TABLE_",
+    "SIG_GROUP_",
+    "SIG_VALTYPE_",
+    "SIGTYPE_VALTYPE_",
+    "BO_TX_BU_",
+    "BA_DEF_REL_",
+    "BA_REL_",
+    "BA_DEF_DEF_REL_",
+    "BU_SG_REL_",
+    "BU_EV_REL_",
+    "BU_BO_REL_",
+    "SG_MUL_VAL_",
+];
+
+impl NewSymbols {
+    /// The complete symbol list Vector tools write, for generated files that should open
+    /// in any tool whatever sections they use.
+    pub fn standard() -> Self {
+        NewSymbols(STANDARD_SYMBOLS.iter().map(ToString::to_string).collect())
+    }
+
+    /// Whether `symbol` (e.g. `BA_DEF_`) is declared.
+    pub fn supports_symbol(&self, symbol: &str) -> bool {
+        self.0.iter().any(|s| s == symbol)
+    }
+}
+
 fn parser_one_line_new_symbols(input: &str) -> IResult<&str, String, DbcParseError> {
     map(
-        (space0, dbc_object_name, space0, line_ending),
+        (multispace0, dbc_object_name, space0, line_ending),
         |(_, name, _, _)| name.to_owned(),
     )
     .parse(input)
@@ -145,6 +192,36 @@ NS_ :
         }
     }
 
+    #[test]
+    fn test_new_symbols_supports_symbol_01() {
+        let names = NewSymbols(vec!["NS_DESC_".into(), "CM_".into()]);
+        assert!(names.supports_symbol("CM_"));
+        assert!(!names.supports_symbol("BA_DEF_"));
+        assert!(!names.supports_symbol("CM"));
+    }
+
+    #[test]
+    fn test_parser_new_symbols_03() {
+        let ret = parser_new_symbols(
+            "NS_ :
+	NS_DESC_
+	CM_
+
+	BA_DEF_
+	BA_
+
+	VAL_
+BS_:
+",
+        );
+        let (remain, names) = ret.unwrap();
+        assert_eq!(remain, "BS_:\n");
+        assert_eq!(
+            names.to_string(),
+            "NS_:\n\tNS_DESC_\n\tCM_\n\tBA_DEF_\n\tBA_\n\tVAL_\n"
+        );
+    }
+
     #[test]
     fn test_new_symbol_string_01() {
         let names = NewSymbols(vec!["NS_DESC_".into(), "CM_".into()]);
@@ -156,4 +233,13 @@ NS_ :
         let names = NewSymbols(vec![]);
         assert_eq!(format!("{names}"), "NS_:\n");
     }
+
+    #[test]
+    fn test_new_symbols_standard_01() {
+        let names = NewSymbols::standard();
+        assert_eq!(names.0.len(), 28);
+        assert!(names.supports_symbol("BA_DEF_DEF_"));
+        assert!(names.supports_symbol("SG_MUL_VAL_"));
+        assert_eq!(parser_new_symbols(&names.to_string()), Ok(("", names)));
+    }
 }
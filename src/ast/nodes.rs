@@ -6,9 +6,20 @@ use nom::combinator::map;
 use nom::multi::many0;
 use nom::{IResult, Parser};
 
-use super::common_parsers::{multispacey, parser_node_name, spacey};
+use super::common_parsers::{
+    dbc_identifier, dbc_identifier_01, multispacey, parser_node_name, spacey,
+};
 use super::error::DbcParseError;
 
+/// Placeholder node name used where a transmitter or receiver is required but there is
+/// none.
+pub const VECTOR_XXX: &str = "Vector__XXX";
+
+/// Whether `name` is the [`VECTOR_XXX`] placeholder rather than a real node.
+pub fn is_vector_xxx(name: &str) -> bool {
+    name == VECTOR_XXX
+}
+
 /// List of all CAN-Nodes, seperated by whitespaces.
 ///
 /// The node section defines the names of all participating nodes. The names defined
@@ -30,7 +41,7 @@ pub struct Nodes(pub Vec<String>);
 
 impl fmt::Display for Nodes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "BU_:",)?;
+        write!(f, "BU_:")?;
         for node in &self.0 {
             write!(f, " {node}")?;
         }
@@ -39,6 +50,19 @@ impl fmt::Display for Nodes {
     }
 }
 
+/// Whether the node list stopped at a node named after a keyword, e.g. `CM_` in
+/// `BU_: ECU CM_`. The next statement may follow on the same line, as in
+/// `BU_: BO_ 100 First: 8 ECU`, so only a rest of the line made of names alone, with no
+/// numbers, `:` or strings, is taken as node names.
+fn keyword_node_name(remain: &str) -> bool {
+    let line = remain.lines().next().unwrap_or_default();
+    let mut names = line.split_whitespace().peekable();
+    matches!(
+        names.peek().map(|name| dbc_identifier(name)),
+        Some(Err(nom::Err::Error(DbcParseError::UseKeywordAsIdentifier)))
+    ) && names.all(|name| matches!(dbc_identifier_01(name), Ok(("", _))))
+}
+
 pub fn parser_nodes(input: &str) -> IResult<&str, Nodes, DbcParseError> {
     let res = map(
         (
@@ -51,6 +75,10 @@ pub fn parser_nodes(input: &str) -> IResult<&str, Nodes, DbcParseError> {
     )
     .parse(input);
     match res {
+        Ok((remain, _)) if keyword_node_name(remain) => {
+            log::trace!("parse nodes failed, keyword used as node name");
+            Err(nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier))
+        }
         Ok((remain, can_nodes)) => {
             log::info!("parse nodes: {:?}", can_nodes.0);
             Ok((remain, can_nodes))
@@ -65,6 +93,24 @@ pub fn parser_nodes(input: &str) -> IResult<&str, Nodes, DbcParseError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::message::parser_dbc_message;
+
+    #[test]
+    fn test_dbc_can_nodes_05() {
+        assert_eq!(
+            parser_nodes("BU_: ECU CM_\n"),
+            Err(nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier))
+        );
+        assert_eq!(
+            parser_nodes("BU_: ECU CM_ Gateway\n"),
+            Err(nom::Err::Failure(DbcParseError::UseKeywordAsIdentifier))
+        );
+        assert!(parser_nodes("BU_: ECU BO_ 100 First: 8 ECU\n").is_ok());
+        assert_eq!(
+            parser_nodes("BU_: CM_Gateway FILTERED_ECU\n"),
+            Ok(("", Nodes(vec!["CM_Gateway".into(), "FILTERED_ECU".into()])))
+        );
+    }
 
     #[test]
     fn test_dbc_can_nodes_01() {
@@ -97,6 +143,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dbc_can_nodes_04() {
+        let input = "BU_:\nBO_ 100 First: 8 Vector__XXX\n SG_ Speed : 0|8@1+ (1,0) [0|0] \"\" Vector__XXX\n";
+        let (remain, nodes) = parser_nodes(input).unwrap();
+        assert_eq!(nodes, Nodes(vec![]));
+        assert!(remain.starts_with("BO_ 100 First"));
+        let (remain, message) = parser_dbc_message(remain).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(message.header.name, "First");
+        assert_eq!(message.signals.len(), 1);
+
+        let (remain, nodes) = parser_nodes("BU_: BO_ 100 First: 8 Vector__XXX").unwrap();
+        assert_eq!(nodes, Nodes(vec![]));
+        assert_eq!(remain, "BO_ 100 First: 8 Vector__XXX");
+    }
+
     #[test]
     fn test_nodes_string_01() {
         assert_eq!(
@@ -0,0 +1,147 @@
+use std::fmt;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::map;
+use nom::multi::many0;
+use nom::{IResult, Parser};
+
+use super::common_parsers::{multispacey, spacey};
+use super::error::DbcParseError;
+
+/// A section whose content is not modeled and is kept as raw text, so that files using
+/// it still parse and round-trip.
+///
+/// Used for signal type value descriptions (`SGTYPE_VAL_`) and signal type attribute
+/// values (`BA_SGTYPE_`).
+///
+/// example:
+///
+/// ```text
+/// SGTYPE_VAL_ SpeedType 0 "Stop" 1 "Move" ;
+/// BA_SGTYPE_ "SGTypeAttr" SGTYPE_ SpeedType 5;
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawSection {
+    pub keyword: String,
+    /// Everything between the keyword and the terminating `;`, trimmed.
+    pub content: String,
+}
+
+impl fmt::Display for RawSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {};", self.keyword, self.content)
+    }
+}
+
+/// The text up to the first `;` that is not inside a quoted string.
+fn raw_content(input: &str) -> IResult<&str, &str, DbcParseError> {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in input.char_indices() {
+        match c {
+            '\\' if in_string && !escaped => {
+                escaped = true;
+                continue;
+            }
+            '"' if !escaped => in_string = !in_string,
+            ';' if !in_string => return Ok((&input[i..], &input[..i])),
+            _ => {}
+        }
+        escaped = false;
+    }
+    Err(nom::Err::Error(DbcParseError::Unparseable))
+}
+
+fn parser_raw_section<'a>(
+    keyword: &'static str,
+    input: &'a str,
+) -> IResult<&'a str, RawSection, DbcParseError> {
+    map(
+        (
+            multispacey(tag(keyword)),
+            spacey(raw_content),
+            spacey(tag(";")),
+            many0(line_ending),
+        ),
+        |(_, content, _, _)| RawSection {
+            keyword: keyword.to_string(),
+            content: content.trim().to_string(),
+        },
+    )
+    .parse(input)
+}
+
+pub fn parser_signal_type_value_descriptions(
+    input: &str,
+) -> IResult<&str, RawSection, DbcParseError> {
+    match parser_raw_section("SGTYPE_VAL_", input) {
+        Ok((remain, val)) => {
+            log::info!("parse signal type value descriptions: {val:?}");
+            Ok((remain, val))
+        }
+        Err(e) => {
+            log::trace!("parse signal type value descriptions failed, e = {e:?}");
+            Err(nom::Err::Error(
+                DbcParseError::BadSignalTypeValueDescriptions,
+            ))
+        }
+    }
+}
+
+pub fn parser_signal_type_attribute_value(input: &str) -> IResult<&str, RawSection, DbcParseError> {
+    match parser_raw_section("BA_SGTYPE_", input) {
+        Ok((remain, val)) => {
+            log::info!("parse signal type attribute value: {val:?}");
+            Ok((remain, val))
+        }
+        Err(e) => {
+            log::trace!("parse signal type attribute value failed, e = {e:?}");
+            Err(nom::Err::Error(DbcParseError::BadSignalTypeAttributeValue))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parser_signal_type_value_descriptions_01() {
+        let (remain, section) = parser_signal_type_value_descriptions(
+            "SGTYPE_VAL_ SpeedType 0 \"Stop; Park\" 1 \"Move\" ;\nVAL_ 1 Speed 0 \"Stop\" ;",
+        )
+        .unwrap();
+        assert_eq!(remain, "VAL_ 1 Speed 0 \"Stop\" ;");
+        assert_eq!(
+            section,
+            RawSection {
+                keyword: "SGTYPE_VAL_".to_string(),
+                content: "SpeedType 0 \"Stop; Park\" 1 \"Move\"".to_string(),
+            }
+        );
+        assert_eq!(
+            section.to_string(),
+            "SGTYPE_VAL_ SpeedType 0 \"Stop; Park\" 1 \"Move\";"
+        );
+    }
+
+    #[test]
+    fn test_parser_signal_type_attribute_value_01() {
+        assert_eq!(
+            parser_signal_type_attribute_value(r#"BA_SGTYPE_ "SGTypeAttr" SGTYPE_ SpeedType 5;"#),
+            Ok((
+                "",
+                RawSection {
+                    keyword: "BA_SGTYPE_".to_string(),
+                    content: r#""SGTypeAttr" SGTYPE_ SpeedType 5"#.to_string(),
+                }
+            ))
+        );
+        assert_eq!(
+            parser_signal_type_attribute_value(r#"BA_ "SGTypeAttr" 5;"#),
+            Err(nom::Err::Error(DbcParseError::BadSignalTypeAttributeValue))
+        );
+    }
+}
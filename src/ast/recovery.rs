@@ -0,0 +1,458 @@
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::{IResult, Parser};
+
+use super::attribute_default::{parser_attribute_default, AttributeDefault};
+use super::attribute_definition::{parser_attribute_definition, AttributeDefinition};
+use super::attribute_value::{parser_object_attribute_value, ObjectAttributeValue};
+use super::bit_timing::{parser_bit_timing, BitTiming};
+use super::comment::{parser_comment, Comment};
+use super::env_var::{parser_env_var, EnvironmentVariable};
+use super::env_var_data::{parser_env_var_data, EnvironmentVariableData};
+use super::env_var_value_descriptions::{
+    parser_env_var_value_descriptions, EnvironmentVariableValueDescriptions,
+};
+use super::error::{DbcParseError, PositionedError};
+use super::extended_multiplexing::{parser_extended_multiplexing, ExtendedMultiplexing};
+use super::message::{parser_dbc_message_strict, Message};
+use super::message_transmitters::{parser_message_transmitters, MessageTransmitters};
+use super::network_ast::NetworkAst;
+use super::new_symbols::{parser_new_symbols, NewSymbols};
+use super::nodes::{parser_nodes, Nodes};
+use super::raw_section::{
+    parser_signal_type_attribute_value, parser_signal_type_value_descriptions, RawSection,
+};
+use super::signal_extended_value_type::{
+    parser_signal_extended_value_type, SignalExtendedValueTypeList,
+};
+use super::signal_group::{parser_signal_group, SignalGroup};
+use super::signal_value_descriptions::{parser_signal_value_descriptions, SignalValueDescriptions};
+use super::value_tables::{parser_value_table, ValueTable};
+use super::version::{parser_version, Version};
+use crate::error::DbcError;
+
+/// Keywords that start a section at the beginning of a line. Parsing resumes at the next
+/// of these after an error.
+const SECTION_KEYWORDS: &[&str] = &[
+    "VERSION",
+    "NS_",
+    "BS_",
+    "BU_",
+    "VAL_TABLE_",
+    "BO_",
+    "BO_TX_BU_",
+    "EV_",
+    "ENVVAR_DATA_",
+    "EV_DATA_",
+    "SGTYPE_",
+    "SGTYPE_VAL_",
+    "SIG_TYPE_REF_",
+    "SIG_GROUP_",
+    "CM_",
+    "BA_DEF_",
+    "BA_DEF_SGTYPE_",
+    "BA_DEF_REL_",
+    "BA_DEF_DEF_",
+    "BA_DEF_DEF_REL_",
+    "BA_",
+    "BA_SGTYPE_",
+    "BA_REL_",
+    "BU_SG_REL_",
+    "BU_EV_REL_",
+    "BU_BO_REL_",
+    "VAL_",
+    "CAT_DEF_",
+    "CAT_",
+    "FILTER",
+    "SIG_VALTYPE_",
+    "SIGTYPE_VALTYPE_",
+    "SG_MUL_VAL_",
+];
+
+/// A single top-level statement of a DBC file.
+enum Section {
+    Version(Version),
+    NewSymbols(NewSymbols),
+    BitTiming(Option<BitTiming>),
+    Nodes(Nodes),
+    ValueTable(ValueTable),
+    Message(Message),
+    MessageTransmitters(MessageTransmitters),
+    EnvVar(EnvironmentVariable),
+    EnvVarData(EnvironmentVariableData),
+    Comment(Comment),
+    AttributeDefinition(AttributeDefinition),
+    AttributeDefault(AttributeDefault),
+    AttributeValue(ObjectAttributeValue),
+    SignalTypeAttributeValue(RawSection),
+    SignalValueDescriptions(SignalValueDescriptions),
+    EnvVarValueDescriptions(EnvironmentVariableValueDescriptions),
+    SignalTypeValueDescriptions(RawSection),
+    SignalGroup(SignalGroup),
+    SignalExtendedValueType(SignalExtendedValueTypeList),
+    ExtendedMultiplexing(ExtendedMultiplexing),
+}
+
+/// The sections found so far, in any order.
+#[derive(Default)]
+pub(crate) struct PartialNetwork {
+    version: Option<Version>,
+    new_symbols: Option<NewSymbols>,
+    bit_timing: Option<BitTiming>,
+    nodes: Option<Nodes>,
+    value_tables: Vec<ValueTable>,
+    messages: Vec<Message>,
+    message_transmitters: Vec<MessageTransmitters>,
+    env_vars: Vec<EnvironmentVariable>,
+    env_vars_data: Vec<EnvironmentVariableData>,
+    comments: Vec<Comment>,
+    attribute_definitions: Vec<AttributeDefinition>,
+    attribute_defaults: Vec<AttributeDefault>,
+    attribute_values: Vec<ObjectAttributeValue>,
+    signal_type_attribute_values: Vec<RawSection>,
+    signal_value_descriptions: Vec<SignalValueDescriptions>,
+    env_var_value_descriptions: Vec<EnvironmentVariableValueDescriptions>,
+    signal_type_value_descriptions: Vec<RawSection>,
+    signal_groups: Vec<SignalGroup>,
+    signal_extended_value_types: Vec<SignalExtendedValueTypeList>,
+    extended_multiplexings: Vec<ExtendedMultiplexing>,
+}
+
+impl PartialNetwork {
+    fn push(&mut self, section: Section) {
+        match section {
+            Section::Version(v) => self.version = Some(v),
+            Section::NewSymbols(v) => self.new_symbols = Some(v),
+            Section::BitTiming(v) => self.bit_timing = v,
+            Section::Nodes(v) => self.nodes = Some(v),
+            Section::ValueTable(v) => self.value_tables.push(v),
+            Section::Message(v) => self.messages.push(v),
+            Section::MessageTransmitters(v) => self.message_transmitters.push(v),
+            Section::EnvVar(v) => self.env_vars.push(v),
+            Section::EnvVarData(v) => self.env_vars_data.push(v),
+            Section::Comment(v) => self.comments.push(v),
+            Section::AttributeDefinition(v) => self.attribute_definitions.push(v),
+            Section::AttributeDefault(v) => self.attribute_defaults.push(v),
+            Section::AttributeValue(v) => self.attribute_values.push(v),
+            Section::SignalTypeAttributeValue(v) => self.signal_type_attribute_values.push(v),
+            Section::SignalValueDescriptions(v) => self.signal_value_descriptions.push(v),
+            Section::EnvVarValueDescriptions(v) => self.env_var_value_descriptions.push(v),
+            Section::SignalTypeValueDescriptions(v) => self.signal_type_value_descriptions.push(v),
+            Section::SignalGroup(v) => self.signal_groups.push(v),
+            Section::SignalExtendedValueType(v) => self.signal_extended_value_types.push(v),
+            Section::ExtendedMultiplexing(v) => self.extended_multiplexings.push(v),
+        }
+    }
+
+    /// The network, or the error for the first of the required `VERSION`, `NS_` and
+    /// `BU_` sections that is missing.
+    pub(crate) fn into_network(self) -> Result<NetworkAst, DbcParseError> {
+        let mut network = NetworkAst {
+            version: self.version.ok_or(DbcParseError::BadVersion)?,
+            new_symbols: self.new_symbols.ok_or(DbcParseError::BadNames)?,
+            bit_timing: self.bit_timing,
+            nodes: self.nodes.ok_or(DbcParseError::BadCanNodes)?,
+            value_tables: Some(self.value_tables).filter(|v| !v.is_empty()),
+            messages: self.messages,
+            message_transmitters: self.message_transmitters,
+            env_vars: self.env_vars,
+            env_vars_data: self.env_vars_data,
+            comments: self.comments,
+            attribute_definitions: self.attribute_definitions,
+            attribute_defaults: self.attribute_defaults,
+            attribute_values: self.attribute_values,
+            signal_type_attribute_values: self.signal_type_attribute_values,
+            signal_value_descriptions: self.signal_value_descriptions,
+            env_var_value_descriptions: self.env_var_value_descriptions,
+            signal_type_value_descriptions: self.signal_type_value_descriptions,
+            signal_groups: self.signal_groups,
+            signal_extended_value_types: self.signal_extended_value_types,
+            extended_multiplexings: self.extended_multiplexings,
+        };
+        network.resolve_attribute_defaults();
+        Ok(network)
+    }
+}
+
+/// The keyword at the start of `input`, e.g. `BO_` for `BO_ 100 ...` or `BU_` for
+/// `BU_: ...`.
+fn section_keyword(input: &str) -> &str {
+    let end = input
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(input.len());
+    &input[..end]
+}
+
+fn parser_section<'a>(keyword: &str, input: &'a str) -> IResult<&'a str, Section, DbcParseError> {
+    match keyword {
+        "VERSION" => map(parser_version, Section::Version).parse(input),
+        "NS_" => map(parser_new_symbols, Section::NewSymbols).parse(input),
+        "BS_" => map(parser_bit_timing, Section::BitTiming).parse(input),
+        "BU_" => map(parser_nodes, Section::Nodes).parse(input),
+        "VAL_TABLE_" => map(parser_value_table, Section::ValueTable).parse(input),
+        "BO_" => map(parser_dbc_message_strict, Section::Message).parse(input),
+        "BO_TX_BU_" => map(parser_message_transmitters, Section::MessageTransmitters).parse(input),
+        "EV_" => map(parser_env_var, Section::EnvVar).parse(input),
+        "ENVVAR_DATA_" => map(parser_env_var_data, Section::EnvVarData).parse(input),
+        "CM_" => map(parser_comment, Section::Comment).parse(input),
+        "BA_DEF_" | "BA_DEF_REL_" => {
+            map(parser_attribute_definition, Section::AttributeDefinition).parse(input)
+        }
+        "BA_DEF_DEF_" | "BA_DEF_DEF_REL_" => {
+            map(parser_attribute_default, Section::AttributeDefault).parse(input)
+        }
+        "BA_" | "BA_REL_" => {
+            map(parser_object_attribute_value, Section::AttributeValue).parse(input)
+        }
+        "BA_SGTYPE_" => map(
+            parser_signal_type_attribute_value,
+            Section::SignalTypeAttributeValue,
+        )
+        .parse(input),
+        "SGTYPE_VAL_" => map(
+            parser_signal_type_value_descriptions,
+            Section::SignalTypeValueDescriptions,
+        )
+        .parse(input),
+        "VAL_" => alt((
+            map(
+                parser_signal_value_descriptions,
+                Section::SignalValueDescriptions,
+            ),
+            map(
+                parser_env_var_value_descriptions,
+                Section::EnvVarValueDescriptions,
+            ),
+        ))
+        .parse(input),
+        "SIG_GROUP_" => map(parser_signal_group, Section::SignalGroup).parse(input),
+        "SIG_VALTYPE_" => map(
+            parser_signal_extended_value_type,
+            Section::SignalExtendedValueType,
+        )
+        .parse(input),
+        "SG_MUL_VAL_" => {
+            map(parser_extended_multiplexing, Section::ExtendedMultiplexing).parse(input)
+        }
+        _ => Err(nom::Err::Error(DbcParseError::UnknownSection(
+            keyword.to_string(),
+        ))),
+    }
+}
+
+/// The next line after the start of `input` that begins with a section keyword, or the
+/// end of `input`.
+fn next_section(input: &str) -> &str {
+    let mut rest = input;
+    while let Some(i) = rest.find('\n') {
+        rest = &rest[i + 1..];
+        if SECTION_KEYWORDS.contains(&section_keyword(rest)) {
+            return rest;
+        }
+    }
+    ""
+}
+
+fn position(input: &str, remain: &str) -> (usize, usize) {
+    let consumed = &input[..input.len() - remain.len()];
+    let line = consumed.matches('\n').count() + 1;
+    let column = consumed
+        .rsplit('\n')
+        .next()
+        .map_or(0, |l| l.chars().count())
+        + 1;
+    (line, column)
+}
+
+/// Parse every section of `input` one at a time, in any order. A section that fails to
+/// parse is reported and skipped up to the next line starting with a section keyword.
+pub(crate) fn parse_sections(input: &str) -> (PartialNetwork, Vec<PositionedError>) {
+    let mut network = PartialNetwork::default();
+    let mut errors = vec![];
+    let mut remain = input.trim_start();
+    while !remain.is_empty() {
+        let keyword = section_keyword(remain);
+        match parser_section(keyword, remain) {
+            Ok((rest, section)) if rest.len() < remain.len() => {
+                network.push(section);
+                remain = rest.trim_start();
+            }
+            result => {
+                let error = match result {
+                    Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e,
+                    _ => DbcParseError::Unparseable,
+                };
+                let (line, column) = position(input, remain);
+                log::error!("{line}:{column}: {error}");
+                errors.push(PositionedError {
+                    line,
+                    column,
+                    error,
+                });
+                remain = next_section(remain).trim_start();
+            }
+        }
+    }
+    (network, errors)
+}
+
+/// Parse `input`, carrying on after errors to report as many as possible, e.g. for
+/// editor diagnostics.
+///
+/// The network holds every section that parsed and is `None` only when a required
+/// `VERSION`, `NS_` or `BU_` section is missing; that is reported as an error at the end
+/// of the input.
+pub fn parse_dbc_collect_errors(input: &str) -> (Option<NetworkAst>, Vec<PositionedError>) {
+    let (network, mut errors) = parse_sections(input);
+    match network.into_network() {
+        Ok(network) => (Some(network), errors),
+        Err(error) => {
+            let (line, column) = position(input, "");
+            errors.push(PositionedError {
+                line,
+                column,
+                error,
+            });
+            (None, errors)
+        }
+    }
+}
+
+/// Parse a network split across fragments that are concatenated at build time, e.g. a
+/// header fragment with `VERSION`, `NS_` and `BU_` and fragments holding only messages.
+///
+/// Fragments need not repeat the header, and sections may come in any order across
+/// them. The first section that fails to parse is returned as the error.
+pub fn parse_dbc_fragments(fragments: &[&str]) -> Result<NetworkAst, DbcError> {
+    let input = fragments.join("\n");
+    let (network, errors) = parse_sections(&input);
+    if let Some(error) = errors.into_iter().next() {
+        return Err(DbcError::ParseError(error.error));
+    }
+    network.into_network().map_err(DbcError::ParseError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::attribute_default::AttributeValue;
+    use crate::ast::network_ast::parse_dbc;
+
+    #[test]
+    fn test_parse_dbc_collect_errors_01() {
+        let input = std::fs::read_to_string("dbc/mytest/abs.dbc").unwrap();
+        let (network, errors) = parse_dbc_collect_errors(&input);
+        assert_eq!(errors, vec![]);
+        assert_eq!(network, Some(parse_dbc(&input).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_dbc_collect_errors_02() {
+        let (network, errors) = parse_dbc_collect_errors(
+            r#"VERSION ""
+
+NS_:
+    CM_
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+ SG_ Speed : 0|16@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Broken : 16|x@1+ (1,0) [0|0] "" Vector__XXX
+
+BO_ 200 Second: 8 ECU
+ SG_ Load : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+CM_ BO_ 200 "missing quote;
+CM_ BO_ 100 "First message";
+"#,
+        );
+        assert_eq!(
+            errors,
+            vec![
+                PositionedError {
+                    line: 10,
+                    column: 1,
+                    error: DbcParseError::MalformedSignal(
+                        r#"SG_ Broken : 16|x@1+ (1,0) [0|0] "" Vector__XXX"#.into()
+                    ),
+                },
+                PositionedError {
+                    line: 17,
+                    column: 1,
+                    error: DbcParseError::BadComment,
+                },
+            ]
+        );
+        assert_eq!(errors[1].to_string(), "17:1: bad comment");
+
+        let network = network.unwrap();
+        assert_eq!(network.messages.len(), 1);
+        assert_eq!(network.messages[0].header.name, "Second");
+        assert_eq!(network.comments.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_dbc_collect_errors_03() {
+        let (network, errors) = parse_dbc_collect_errors("VERSION \"\"\n\nNS_:\n");
+        assert_eq!(network, None);
+        assert_eq!(
+            errors,
+            vec![PositionedError {
+                line: 4,
+                column: 1,
+                error: DbcParseError::BadCanNodes,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_dbc_fragments_01() {
+        let header = r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+"#;
+        let messages = r#"BO_ 100 First: 8 ECU
+ SG_ Speed : 0|16@1+ (1,0) [0|0] "" Vector__XXX
+
+CM_ BO_ 100 "First message";"#;
+        let network = parse_dbc_fragments(&[header, messages]).unwrap();
+        assert_eq!(network, parse_dbc(&[header, messages].join("\n")).unwrap());
+        assert_eq!(network.messages[0].signals[0].name, "Speed");
+
+        assert_eq!(
+            parse_dbc_fragments(&[messages]),
+            Err(DbcError::ParseError(DbcParseError::BadVersion))
+        );
+    }
+
+    #[test]
+    fn test_resolve_attribute_defaults_02() {
+        let input = r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BA_DEF_ BO_ "D" FLOAT 0 10;
+BA_DEF_DEF_ "D" "2.5";
+"#;
+        let network = parse_dbc(input).unwrap();
+        assert_eq!(
+            network.attribute_default("D"),
+            Some(&AttributeValue::Double(2.5))
+        );
+        let (collected, errors) = parse_dbc_collect_errors(input);
+        assert!(errors.is_empty());
+        assert_eq!(collected, Some(network.clone()));
+        assert_eq!(parse_dbc_fragments(&[input]).unwrap(), network);
+    }
+}
@@ -2,8 +2,8 @@ use std::fmt;
 
 use nom::branch::alt;
 use nom::bytes::complete::tag;
-use nom::character::complete::line_ending;
-use nom::combinator::{map, opt};
+use nom::character::complete::{line_ending, space1};
+use nom::combinator::{map, opt, value};
 use nom::multi::{many0, separated_list0};
 use nom::sequence::{delimited, pair, separated_pair};
 use nom::{IResult, Parser};
@@ -13,6 +13,9 @@ use super::common_parsers::{
     multispacey, number_value, parser_node_name, parser_signal_name, spacey, unsigned_integer,
 };
 use super::error::DbcParseError;
+use super::nodes::is_vector_xxx;
+use super::signal_extended_value_type::SignalExtendedValueType;
+use crate::error::{DecodeError, EncodeError};
 
 /// example:
 ///
@@ -146,6 +149,8 @@ impl fmt::Display for Signal {
             ValueType::Unsigned => "+",
         };
         let byte_order = &self.byte_order.to_string();
+        // floats are written in their shortest form that parses back to the same value,
+        // so `[0.0|0.0]` becomes `[0|0]`
         let min_max = match (&self.min, &self.max) {
             (Some(min), Some(max)) => format!("[{min}|{max}]"),
             _ => String::new(),
@@ -177,6 +182,401 @@ impl fmt::Display for Signal {
     }
 }
 
+/// How [`Signal::encode`] handles a physical value outside of the signal's range.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum EncodeMode {
+    /// Clamp the value to the declared `[min|max]` range, or to the range the bit width
+    /// can hold if no range is declared.
+    #[default]
+    Clamp,
+    /// Keep only the low `size` bits of the raw value, wrapping around like the bus would.
+    Wrap,
+    /// Reject the value with [`EncodeError::OutOfRange`].
+    Error,
+}
+
+/// A decoded signal value that keeps track of what kind of value it is, so a raw value
+/// such as an enum index is not mistaken for a physical value.
+#[derive(PartialEq, Debug, Clone)]
+pub enum SignalValue {
+    /// The raw integer on the bus, sign extended for signed signals.
+    Raw(i64),
+    /// The raw value with `factor` and `offset` applied.
+    Physical(f64),
+    /// The value description (`VAL_`) of the raw value.
+    Label(String),
+}
+
+impl SignalValue {
+    /// The value as a number: the raw or physical value, `None` for a label.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            #[allow(clippy::cast_precision_loss)]
+            SignalValue::Raw(raw) => Some(*raw as f64),
+            SignalValue::Physical(value) => Some(*value),
+            SignalValue::Label(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for SignalValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignalValue::Raw(raw) => write!(f, "{raw}"),
+            SignalValue::Physical(value) => write!(f, "{value}"),
+            SignalValue::Label(label) => write!(f, "{label}"),
+        }
+    }
+}
+
+impl From<i64> for SignalValue {
+    fn from(raw: i64) -> Self {
+        SignalValue::Raw(raw)
+    }
+}
+
+impl From<f64> for SignalValue {
+    fn from(value: f64) -> Self {
+        SignalValue::Physical(value)
+    }
+}
+
+impl From<String> for SignalValue {
+    fn from(label: String) -> Self {
+        SignalValue::Label(label)
+    }
+}
+
+impl Signal {
+    /// Number of real receivers, not counting `Vector__XXX`.
+    pub fn receiver_count(&self) -> usize {
+        self.receivers
+            .iter()
+            .flatten()
+            .filter(|r| !is_vector_xxx(r))
+            .count()
+    }
+
+    /// Whether both signals decode a payload the same way, ignoring cosmetic fields such
+    /// as the name, `[min|max]`, unit and receivers.
+    #[allow(clippy::float_cmp)]
+    pub fn semantically_eq(&self, other: &Signal) -> bool {
+        self.start_bit == other.start_bit
+            && self.size == other.size
+            && self.byte_order == other.byte_order
+            && self.value_type == other.value_type
+            && self.factor == other.factor
+            && self.offset == other.offset
+    }
+
+    /// Payload bit positions covered by the signal, least significant bit first.
+    ///
+    /// Bit `n` of the payload is bit `n % 8` of byte `n / 8`. For big-endian (Motorola)
+    /// signals `start_bit` is the most significant bit and the remaining bits follow the
+    /// usual sawtooth order.
+    pub fn bit_positions(&self) -> Vec<u32> {
+        match self.byte_order {
+            ByteOrder::LittleEndian => {
+                (self.start_bit..self.start_bit.saturating_add(self.size)).collect()
+            }
+            ByteOrder::BigEndian => {
+                let mut positions = Vec::with_capacity(self.size as usize);
+                let mut pos = self.start_bit;
+                for _ in 0..self.size {
+                    positions.push(pos);
+                    pos = if pos % 8 == 0 {
+                        pos.saturating_add(15)
+                    } else {
+                        pos - 1
+                    };
+                }
+                positions.reverse();
+                positions
+            }
+        }
+    }
+
+    /// Whether the signal is only present for one multiplexer value (`m<n>`).
+    pub fn is_multiplexed(&self) -> bool {
+        self.multiplexer
+            .as_ref()
+            .is_some_and(|m| m.multiplexer_signal.is_some())
+    }
+
+    /// Whether the signal is a multiplexer switch (`M`). A switch of an extended
+    /// multiplexing chain (`m3M`) is also [`Signal::is_multiplexed`].
+    pub fn is_multiplexor(&self) -> bool {
+        self.multiplexer
+            .as_ref()
+            .is_some_and(|m| m.multiplexer_switch.is_some())
+    }
+
+    /// Whether the signal shares a payload bit with `other` in the same frame. Signals
+    /// multiplexed by different multiplexer values (`m1` and `m2`) never share a frame.
+    pub fn overlaps(&self, other: &Signal) -> bool {
+        let mux = |signal: &Signal| {
+            signal
+                .multiplexer
+                .as_ref()
+                .and_then(|m| m.multiplexer_signal)
+        };
+        if let (Some(a), Some(b)) = (mux(self), mux(other)) {
+            if a != b {
+                return false;
+            }
+        }
+        let positions = self.bit_positions();
+        other
+            .bit_positions()
+            .iter()
+            .any(|pos| positions.contains(pos))
+    }
+
+    /// Physical value of the raw value `raw`: `raw * factor + offset`.
+    pub fn raw_to_physical(&self, raw: f64) -> f64 {
+        raw * self.factor + self.offset
+    }
+
+    /// Raw value range the signal's bit width and sign can hold.
+    pub fn raw_range(&self) -> (f64, f64) {
+        let size = f64::from(self.size.min(64));
+        match self.value_type {
+            ValueType::Unsigned => (0.0, (2.0_f64).powf(size) - 1.0),
+            ValueType::Signed if self.size == 0 => (0.0, 0.0),
+            ValueType::Signed => {
+                let half = (2.0_f64).powf(size - 1.0);
+                (-half, half - 1.0)
+            }
+        }
+    }
+
+    /// Exact raw value range of [`Signal::raw_range`], which cannot be held by `f64` for
+    /// 64-bit signals.
+    fn raw_bounds(&self) -> (i128, i128) {
+        let size = self.size.min(64);
+        match self.value_type {
+            _ if size == 0 => (0, 0),
+            ValueType::Unsigned => (0, (1 << size) - 1),
+            ValueType::Signed => (-(1 << (size - 1)), (1 << (size - 1)) - 1),
+        }
+    }
+
+    /// Physical value range implied by the bit width, factor and offset, ignoring any
+    /// declared `[min|max]`.
+    pub fn computed_range(&self) -> (f64, f64) {
+        let (raw_min, raw_max) = self.raw_range();
+        let a = raw_min * self.factor + self.offset;
+        let b = raw_max * self.factor + self.offset;
+        (a.min(b), a.max(b))
+    }
+
+    /// Physical range of the signal: the declared `[min|max]` if it is a real range,
+    /// otherwise [`Signal::computed_range`]. `[0|0]` is commonly written for "no range".
+    fn effective_range(&self) -> (f64, f64) {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if min < max => (min, max),
+            _ => self.computed_range(),
+        }
+    }
+
+    /// Whether the physical value `phys` lies within the declared `[min|max]`, or within
+    /// the range the bit width can hold if no range is declared (missing or `[0|0]`).
+    pub fn value_in_range(&self, phys: f64) -> bool {
+        let (min, max) = self.effective_range();
+        min <= phys && phys <= max
+    }
+
+    /// The physical value `phys` followed by the signal's unit, e.g. `12.5 g`, for
+    /// display. The value is written in its shortest form like in `SG_`, and the unit is
+    /// left out if the signal has none.
+    pub fn format_value(&self, phys: f64) -> String {
+        match &self.unit {
+            Some(unit) if !unit.0.is_empty() => format!("{phys} {unit}"),
+            _ => phys.to_string(),
+        }
+    }
+
+    /// Encode a physical value into `data`, leaving bits outside of the signal untouched.
+    pub fn encode(&self, value: f64, data: &mut [u8], mode: EncodeMode) -> Result<(), EncodeError> {
+        if self.size == 0 || self.size > 64 {
+            return Err(EncodeError::BitRangeInvalid);
+        }
+        let positions = self.bit_positions();
+        match positions.iter().max() {
+            Some(&last) if (last / 8) as usize >= data.len() => {
+                return Err(EncodeError::PayloadTooShort)
+            }
+            _ => {}
+        }
+
+        let (min, max) = self.effective_range();
+        let value = if value < min || value > max {
+            match mode {
+                EncodeMode::Clamp => value.clamp(min, max),
+                EncodeMode::Wrap => value,
+                EncodeMode::Error => return Err(EncodeError::OutOfRange { value, min, max }),
+            }
+        } else {
+            value
+        };
+
+        let raw = ((value - self.offset) / self.factor).round();
+        let (raw_min, raw_max) = self.raw_range();
+        if mode == EncodeMode::Error && (raw < raw_min || raw > raw_max) {
+            return Err(EncodeError::OutOfRange { value, min, max });
+        }
+        // clamp as integers: the f64 bounds of 64-bit signals are rounded past the last
+        // raw value, e.g. to 2^64 instead of u64::MAX
+        #[allow(clippy::cast_possible_truncation)]
+        let raw = raw as i128;
+        let raw = match mode {
+            EncodeMode::Clamp | EncodeMode::Error => {
+                let (raw_min, raw_max) = self.raw_bounds();
+                raw.clamp(raw_min, raw_max)
+            }
+            EncodeMode::Wrap => raw,
+        };
+
+        #[allow(clippy::cast_sign_loss)]
+        let bits = raw as u128;
+        for (i, pos) in positions.into_iter().enumerate() {
+            let byte = &mut data[(pos / 8) as usize];
+            let mask = 1u8 << (pos % 8);
+            if bits >> i & 1 == 1 {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+        }
+        Ok(())
+    }
+
+    /// Raw bits of the signal in `data`, zero extended, or `None` if the signal does not
+    /// fit in the payload.
+    pub fn decode_raw(&self, data: &[u8]) -> Option<u64> {
+        self.try_decode_raw(data).ok()
+    }
+
+    /// Same as [`Signal::decode_raw`], but tells why the signal could not be read.
+    pub fn try_decode_raw(&self, data: &[u8]) -> Result<u64, DecodeError> {
+        if self.size == 0 || self.size > 64 {
+            return Err(DecodeError::BitRangeInvalid);
+        }
+        if self.byte_order == ByteOrder::LittleEndian && self.start_bit % 8 == 0 {
+            self.decode_raw_aligned(data)
+        } else {
+            self.decode_raw_bitwise(data)
+        }
+    }
+
+    /// Fast path of [`Signal::try_decode_raw`] for byte-aligned little-endian signals: the
+    /// bytes covering the signal are loaded as one little-endian word and masked to size.
+    fn decode_raw_aligned(&self, data: &[u8]) -> Result<u64, DecodeError> {
+        let start = (self.start_bit / 8) as usize;
+        let len = self.size.div_ceil(8) as usize;
+        let bytes = data
+            .get(start..start + len)
+            .ok_or(DecodeError::PayloadTooShort)?;
+        let mut word = [0u8; 8];
+        word[..len].copy_from_slice(bytes);
+        let bits = u64::from_le_bytes(word);
+        if self.size == 64 {
+            Ok(bits)
+        } else {
+            Ok(bits & ((1 << self.size) - 1))
+        }
+    }
+
+    /// General path of [`Signal::try_decode_raw`]: reads the signal bit by bit, for any
+    /// byte order and start bit.
+    fn decode_raw_bitwise(&self, data: &[u8]) -> Result<u64, DecodeError> {
+        let mut bits = 0u64;
+        for (i, pos) in self.bit_positions().into_iter().enumerate() {
+            let byte = data
+                .get((pos / 8) as usize)
+                .ok_or(DecodeError::PayloadTooShort)?;
+            if byte >> (pos % 8) & 1 == 1 {
+                bits |= 1 << i;
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Raw value of the signal in `data` as an integer: sign extended from the signal's
+    /// width if it is signed, e.g. `0b111` of a 3-bit signal is `-1`, and zero extended
+    /// otherwise. `None` as for [`Signal::decode_raw`].
+    pub fn decode_raw_signed(&self, data: &[u8]) -> Option<i64> {
+        self.decode_raw(data).map(|bits| self.extend_raw(bits))
+    }
+
+    /// [`Signal::decode_raw_signed`] as a [`SignalValue::Raw`].
+    pub fn decode_raw_value(&self, data: &[u8]) -> Option<SignalValue> {
+        self.decode_raw_signed(data).map(SignalValue::Raw)
+    }
+
+    /// Sign or zero extend raw `bits` according to the signal's value type.
+    fn extend_raw(&self, bits: u64) -> i64 {
+        #[allow(clippy::cast_possible_wrap)]
+        match self.value_type {
+            ValueType::Unsigned => bits as i64,
+            ValueType::Signed => {
+                // two's complement of any width: move the sign bit to bit 63 and shift
+                // back arithmetically
+                let shift = 64 - self.size;
+                (bits << shift) as i64 >> shift
+            }
+        }
+    }
+
+    /// Decode the physical value of the signal from `data`.
+    ///
+    /// `extended_value_type` comes from the signal's `SIG_VALTYPE_` entry, see
+    /// [`NetworkAst::signal_extended_value_type`](super::network_ast::NetworkAst::signal_extended_value_type).
+    /// Float and double signals are read as IEEE 754 values instead of integers, then
+    /// `factor` and `offset` are applied as usual: the DBC format defines the physical
+    /// value as `raw * factor + offset` for every signal, and Vector tools and cantools scale
+    /// float signals the same way. Most files give float signals `(1,0)`, which leaves
+    /// the IEEE value unchanged. Returns `None` if the signal does not
+    /// fit in the payload or its size does not match the float type; use
+    /// [`Signal::try_decode`] to tell these apart.
+    pub fn decode(&self, data: &[u8], extended_value_type: SignalExtendedValueType) -> Option<f64> {
+        self.try_decode(data, extended_value_type).ok()
+    }
+
+    /// Same as [`Signal::decode`], but tells why the signal could not be decoded.
+    pub fn try_decode(
+        &self,
+        data: &[u8],
+        extended_value_type: SignalExtendedValueType,
+    ) -> Result<f64, DecodeError> {
+        let bits = self.try_decode_raw(data)?;
+        let raw = match extended_value_type {
+            SignalExtendedValueType::Integer => match self.value_type {
+                ValueType::Unsigned => {
+                    #[allow(clippy::cast_precision_loss)]
+                    let raw = bits as f64;
+                    raw
+                }
+                ValueType::Signed => {
+                    #[allow(clippy::cast_precision_loss)]
+                    let raw = self.extend_raw(bits) as f64;
+                    raw
+                }
+            },
+            SignalExtendedValueType::Float if self.size == 32 => {
+                #[allow(clippy::cast_possible_truncation)]
+                let raw = f32::from_bits(bits as u32);
+                f64::from(raw)
+            }
+            SignalExtendedValueType::Double if self.size == 64 => f64::from_bits(bits),
+            SignalExtendedValueType::Float | SignalExtendedValueType::Double => {
+                return Err(DecodeError::FloatSizeMismatch)
+            }
+        };
+        Ok(self.raw_to_physical(raw))
+    }
+}
+
 fn parser_signal_multiplexer(input: &str) -> IResult<&str, MultiplexerIndicator, DbcParseError> {
     map(
         (opt(pair(tag("m"), unsigned_integer)), opt(tag("M"))),
@@ -233,24 +633,31 @@ fn parser_signal_factor_offset(input: &str) -> IResult<&str, (f64, f64), DbcPars
     Ok((remain, (factor, offset)))
 }
 
-fn parser_signal_min_max(input: &str) -> IResult<&str, (f64, f64), DbcParseError> {
-    let (remain, (min_value, max_value)) = delimited(
+/// `[min|max]`, or `None` for the empty `[|]` some minimal exports write for "no range".
+fn parser_signal_min_max(input: &str) -> IResult<&str, Option<(f64, f64)>, DbcParseError> {
+    delimited(
         spacey(tag("[")),
-        separated_pair(number_value, spacey(tag("|")), number_value),
+        alt((
+            map(
+                separated_pair(number_value, spacey(tag("|")), number_value),
+                Some,
+            ),
+            value(None, spacey(tag("|"))),
+        )),
         spacey(tag("]")),
     )
-    .parse(input)?;
-
-    Ok((remain, (min_value, max_value)))
+    .parse(input)
 }
 
 fn parser_signal_unit(input: &str) -> IResult<&str, CharString, DbcParseError> {
     parser_char_string(input)
 }
 
+/// Receivers are separated by commas, but some exports use spaces, or a mix of both. The
+/// list ends at the end of the line.
 fn parser_signal_receivers(input: &str) -> IResult<&str, Vec<String>, DbcParseError> {
-    let (remain, nodes) =
-        spacey(separated_list0(tag(","), spacey(parser_node_name))).parse(input)?;
+    let separator = alt((spacey(tag(",")), space1));
+    let (remain, nodes) = spacey(separated_list0(separator, parser_node_name)).parse(input)?;
     Ok((remain, nodes.into_iter().map(String::from).collect()))
 }
 
@@ -298,8 +705,8 @@ pub fn parser_signal(input: &str) -> IResult<&str, Signal, DbcParseError> {
             value_type,
             factor: factor_offset.0,
             offset: factor_offset.1,
-            min: min_max.map(|(min, _)| min),
-            max: min_max.map(|(_, max)| max),
+            min: min_max.flatten().map(|(min, _)| min),
+            max: min_max.flatten().map(|(_, max)| max),
             unit,
             receivers: receiving_nodes,
         },
@@ -313,7 +720,11 @@ pub fn parser_signal(input: &str) -> IResult<&str, Signal, DbcParseError> {
         }
         Err(e) => {
             log::trace!("parse signal failed, e = {e:?}");
-            Err(nom::Err::Error(DbcParseError::BadSignal))
+            match e {
+                // name the actual problem, a keyword used as the signal name
+                nom::Err::Error(DbcParseError::UseKeywordAsIdentifier) => Err(e),
+                _ => Err(nom::Err::Error(DbcParseError::BadSignal)),
+            }
         }
     }
 }
@@ -510,4 +921,530 @@ mod tests {
             Err(err) => panic!("err = {err:?}"),
         }
     }
+
+    #[test]
+    fn test_dbc_signal_05() {
+        assert_eq!(
+            parser_signal(" SG_ X : 0|8@1+ (1,0) [0|255] Node1\n"),
+            Ok((
+                "",
+                Signal {
+                    name: "X".into(),
+                    multiplexer: None,
+                    start_bit: 0,
+                    size: 8,
+                    byte_order: ByteOrder::LittleEndian,
+                    value_type: ValueType::Unsigned,
+                    factor: 1.0,
+                    offset: 0.0,
+                    min: Some(0.0),
+                    max: Some(255.0),
+                    unit: None,
+                    receivers: Some(vec!["Node1".into()]),
+                }
+            )),
+        );
+    }
+
+    #[test]
+    fn test_dbc_signal_06() {
+        let (_, signal) = parser_signal(" SG_ X : 0|8@1+ (1,0) [0|255] Node1,Node2\n").unwrap();
+        assert_eq!(signal.unit, None);
+        assert_eq!(signal.receivers, Some(vec!["Node1".into(), "Node2".into()]));
+
+        let (_, signal) = parser_signal(r#" SG_ X : 0|8@1+ (1,0) [0|255] "" Node1"#).unwrap();
+        assert_eq!(signal.unit, Some(CharString(String::new())));
+        assert_eq!(signal.receivers, Some(vec!["Node1".into()]));
+    }
+
+    #[test]
+    fn test_dbc_signal_07() {
+        let (_, signal) = parser_signal(r#" SG_ AY1 : 32|16@ 1 + (1,0) [0|0] "g" ABS"#).unwrap();
+        assert_eq!(signal.start_bit, 32);
+        assert_eq!(signal.size, 16);
+        assert_eq!(signal.byte_order, ByteOrder::LittleEndian);
+        assert_eq!(signal.value_type, ValueType::Unsigned);
+        assert_eq!(signal.receivers, Some(vec!["ABS".into()]));
+
+        let (_, signal) = parser_signal(r#" SG_ AY1 : 32|16 @ 0 - (1,0) [0|0] "g" ABS"#).unwrap();
+        assert_eq!(signal.byte_order, ByteOrder::BigEndian);
+        assert_eq!(signal.value_type, ValueType::Signed);
+    }
+
+    #[test]
+    fn test_signal_min_max_string_01() {
+        let cases = [
+            ("[0|0]", "[0|0]"),
+            ("[0.0|0.0]", "[0|0]"),
+            ("[-163.84|163.83]", "[-163.84|163.83]"),
+            ("[-4.1768|4.1765]", "[-4.1768|4.1765]"),
+            ("[1e-3|2.5E+2]", "[0.001|250]"),
+            ("[0.30000000000000004|1.5]", "[0.30000000000000004|1.5]"),
+        ];
+        for (range, expected) in cases {
+            let input = format!(r#" SG_ X : 0|16@1+ (0.005,-163.84) {range} "" Node1"#);
+            let (_, signal) = parser_signal(&input).unwrap();
+            let output = signal.to_string();
+            assert!(output.contains(expected), "{range}: {output}");
+
+            let (_, reparsed) = parser_signal(&output).unwrap();
+            assert_eq!(reparsed, signal, "{range}");
+        }
+    }
+
+    #[test]
+    fn test_dbc_signal_08() {
+        let (remain, signal) = parser_signal(
+            " SG_ X : 0|8@1+ (1,0) [0|255] \"unit\"  Node1 Node2 \n SG_ Y : 8|8@1+ (1,0) [0|0] \"\" Node3\n",
+        )
+        .unwrap();
+        assert_eq!(signal.receivers, Some(vec!["Node1".into(), "Node2".into()]));
+        assert!(remain.starts_with(" SG_ Y"));
+        assert_eq!(
+            signal.to_string(),
+            r#"SG_ X : 0|8@1+ (1,0) [0|255] "unit" Node1,Node2"#
+        );
+
+        let (_, signal) =
+            parser_signal(r#" SG_ X : 0|8@1+ (1,0) [0|255] "" Node1, Node2 Node3"#).unwrap();
+        assert_eq!(
+            signal.receivers,
+            Some(vec!["Node1".into(), "Node2".into(), "Node3".into()])
+        );
+    }
+
+    #[test]
+    fn test_dbc_signal_09() {
+        let input = r#"SG_ Wheel-Speed : 0|16@1+ (0.01,0) [0|0] "km/h" ABS"#;
+        let (remain, signal) = parser_signal(input).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(signal.name, "Wheel-Speed");
+        assert_eq!(signal.to_string(), input);
+    }
+
+    #[test]
+    fn test_dbc_signal_10() {
+        let (remain, signal) =
+            parser_signal(r#"SG_ Torque : 0|16@1- (- 0.5, - 1) [- 100|100] "Nm" ECU"#).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!((signal.factor, signal.offset), (-0.5, -1.0));
+        assert_eq!(signal.min, Some(-100.0));
+        assert_eq!(
+            signal.to_string(),
+            r#"SG_ Torque : 0|16@1- (-0.5,-1) [-100|100] "Nm" ECU"#
+        );
+    }
+
+    #[test]
+    fn test_dbc_signal_11() {
+        for range in ["[|]", "[ | ]"] {
+            let input = format!(r#"SG_ Flag : 0|1@1+ (1,0) {range} "" ECU"#);
+            let (remain, signal) = parser_signal(&input).unwrap();
+            assert_eq!(remain, "");
+            assert_eq!((signal.min, signal.max), (None, None));
+            assert_eq!(signal.unit, Some(CharString(String::new())));
+            assert_eq!(signal.receivers, Some(vec!["ECU".into()]));
+        }
+    }
+
+    fn signal_u8(min: f64, max: f64) -> Signal {
+        Signal {
+            name: "Setpoint".into(),
+            multiplexer: None,
+            start_bit: 8,
+            size: 8,
+            byte_order: ByteOrder::LittleEndian,
+            value_type: ValueType::Unsigned,
+            factor: 1.0,
+            offset: 0.0,
+            min: Some(min),
+            max: Some(max),
+            unit: None,
+            receivers: None,
+        }
+    }
+
+    #[test]
+    fn test_signal_is_multiplexed_01() {
+        let mux = |indicator: &str| {
+            let input = format!(r#"SG_ Sig {indicator} : 0|8@1+ (1,0) [0|0] "" ECU"#);
+            let (_, signal) = parser_signal(&input).unwrap();
+            (signal.is_multiplexed(), signal.is_multiplexor())
+        };
+        assert_eq!(mux(""), (false, false));
+        assert_eq!(mux("m2"), (true, false));
+        assert_eq!(mux("M"), (false, true));
+        assert_eq!(mux("m3M"), (true, true));
+    }
+
+    #[test]
+    fn test_signal_format_value_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        assert_eq!(signal.format_value(12.0), "12");
+        signal.unit = Some(CharString(String::new()));
+        assert_eq!(signal.format_value(-0.25), "-0.25");
+        signal.unit = Some(CharString("g".into()));
+        assert_eq!(signal.format_value(12.5), "12.5 g");
+    }
+
+    #[test]
+    fn test_signal_value_in_range_01() {
+        let signal = signal_u8(10.0, 200.0);
+        assert!(signal.value_in_range(10.0));
+        assert!(signal.value_in_range(200.0));
+        assert!(!signal.value_in_range(9.5));
+        assert!(!signal.value_in_range(250.0));
+    }
+
+    #[test]
+    fn test_signal_value_in_range_02() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.min = None;
+        signal.max = None;
+        signal.factor = 0.5;
+        signal.offset = -40.0;
+        assert!(signal.value_in_range(-40.0));
+        assert!(signal.value_in_range(87.5));
+        assert!(!signal.value_in_range(88.0));
+        assert!(!signal.value_in_range(-40.5));
+
+        signal.min = Some(0.0);
+        signal.max = Some(0.0);
+        assert!(signal.value_in_range(87.5));
+    }
+
+    #[test]
+    fn test_signal_receiver_count_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        assert_eq!(signal.receiver_count(), 0);
+        signal.receivers = Some(vec!["Vector__XXX".into()]);
+        assert_eq!(signal.receiver_count(), 0);
+        signal.receivers = Some(vec!["ABS".into()]);
+        assert_eq!(signal.receiver_count(), 1);
+        signal.receivers = Some(vec!["ABS".into(), "Gateway".into(), "Dashboard".into()]);
+        assert_eq!(signal.receiver_count(), 3);
+    }
+
+    #[test]
+    fn test_signal_semantically_eq_01() {
+        let a = signal_u8(0.0, 200.0);
+        let mut b = signal_u8(0.0, 100.0);
+        b.unit = Some(CharString("km/h".into()));
+        b.receivers = Some(vec!["ABS".into()]);
+        assert_ne!(a, b);
+        assert!(a.semantically_eq(&b));
+
+        b.factor = 0.5;
+        assert!(!a.semantically_eq(&b));
+    }
+
+    #[test]
+    fn test_signal_bit_positions_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.start_bit = 7;
+        signal.size = 12;
+        signal.byte_order = ByteOrder::BigEndian;
+        assert_eq!(
+            signal.bit_positions(),
+            vec![12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7]
+        );
+    }
+
+    #[test]
+    fn test_signal_overlaps_01() {
+        let mut a = signal_u8(0.0, 0.0);
+        let mut b = signal_u8(0.0, 0.0);
+        b.start_bit = 15;
+        b.byte_order = ByteOrder::BigEndian;
+        assert!(a.overlaps(&b));
+        b.start_bit = 7;
+        assert!(!a.overlaps(&b));
+        b.start_bit = 15;
+        a.multiplexer = Some(MultiplexerIndicator {
+            multiplexer_signal: Some(1),
+            multiplexer_switch: None,
+        });
+        b.multiplexer = Some(MultiplexerIndicator {
+            multiplexer_signal: Some(2),
+            multiplexer_switch: None,
+        });
+        assert!(!a.overlaps(&b));
+    }
+
+    #[test]
+    fn test_signal_encode_clamp_01() {
+        let mut data = [0u8; 2];
+        let ret = signal_u8(0.0, 200.0).encode(250.0, &mut data, EncodeMode::Clamp);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(data, [0, 200]);
+    }
+
+    #[test]
+    fn test_signal_encode_clamp_02() {
+        let mut data = [0u8; 2];
+        let ret = signal_u8(0.0, 0.0).encode(300.0, &mut data, EncodeMode::default());
+        assert_eq!(ret, Ok(()));
+        assert_eq!(data, [0, 255]);
+    }
+
+    #[test]
+    fn test_signal_encode_clamp_03() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.start_bit = 0;
+        signal.size = 64;
+        let mut data = [0u8; 8];
+        let ret = signal.encode(1e30, &mut data, EncodeMode::Clamp);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(data, [0xFF; 8]);
+
+        signal.value_type = ValueType::Signed;
+        let ret = signal.encode(-1e30, &mut data, EncodeMode::Clamp);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(data, i64::MIN.to_le_bytes());
+        let ret = signal.encode(1e30, &mut data, EncodeMode::Clamp);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(data, i64::MAX.to_le_bytes());
+    }
+
+    #[test]
+    fn test_signal_encode_wrap_01() {
+        let mut data = [0xFFu8; 2];
+        let ret = signal_u8(0.0, 200.0).encode(300.0, &mut data, EncodeMode::Wrap);
+        assert_eq!(ret, Ok(()));
+        assert_eq!(data, [0xFF, 44]);
+    }
+
+    #[test]
+    fn test_signal_encode_error_01() {
+        let mut data = [0u8; 2];
+        let ret = signal_u8(0.0, 200.0).encode(250.0, &mut data, EncodeMode::Error);
+        assert_eq!(
+            ret,
+            Err(EncodeError::OutOfRange {
+                value: 250.0,
+                min: 0.0,
+                max: 200.0
+            })
+        );
+        assert_eq!(data, [0, 0]);
+    }
+
+    #[test]
+    fn test_signal_encode_error_02() {
+        let mut data = [0u8; 1];
+        let ret = signal_u8(0.0, 200.0).encode(10.0, &mut data, EncodeMode::Error);
+        assert_eq!(ret, Err(EncodeError::PayloadTooShort));
+    }
+
+    #[test]
+    fn test_signal_decode_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.value_type = ValueType::Signed;
+        signal.factor = 0.5;
+        signal.offset = 10.0;
+        let data = [0x00, 0xFE];
+        assert_eq!(signal.decode_raw(&data), Some(0xFE));
+        assert_eq!(
+            signal.decode(&data, SignalExtendedValueType::Integer),
+            Some(9.0)
+        );
+        assert_eq!(
+            signal.decode(&data[..1], SignalExtendedValueType::Integer),
+            None
+        );
+    }
+
+    #[test]
+    fn test_signal_try_decode_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        let data = [0x00, 0x7F];
+        assert_eq!(
+            signal.try_decode(&data, SignalExtendedValueType::Integer),
+            Ok(127.0)
+        );
+        assert_eq!(
+            signal.try_decode(&data[..1], SignalExtendedValueType::Integer),
+            Err(DecodeError::PayloadTooShort)
+        );
+        assert_eq!(
+            signal.try_decode(&data, SignalExtendedValueType::Float),
+            Err(DecodeError::FloatSizeMismatch)
+        );
+        assert_eq!(signal.decode(&data, SignalExtendedValueType::Float), None);
+
+        signal.size = 0;
+        assert_eq!(
+            signal.try_decode(&data, SignalExtendedValueType::Integer),
+            Err(DecodeError::BitRangeInvalid)
+        );
+        signal.size = 65;
+        assert_eq!(
+            signal.try_decode_raw(&[0; 16]),
+            Err(DecodeError::BitRangeInvalid)
+        );
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_signal_decode_raw_aligned_01(
+            start_byte in 0u32..10,
+            size in 1u32..=64,
+            data in proptest::collection::vec(proptest::num::u8::ANY, 0..16),
+        ) {
+            let mut signal = signal_u8(0.0, 0.0);
+            signal.start_bit = start_byte * 8;
+            signal.size = size;
+            proptest::prop_assert_eq!(
+                signal.decode_raw_aligned(&data),
+                signal.decode_raw_bitwise(&data)
+            );
+        }
+    }
+
+    #[test]
+    fn test_signal_value_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.value_type = ValueType::Signed;
+        signal.factor = 0.5;
+        let data = [0x00, 0xFE];
+        let raw = signal.decode_raw_value(&data).unwrap();
+        assert_eq!(raw, SignalValue::Raw(-2));
+        assert_eq!(
+            (raw.as_f64(), raw.to_string()),
+            (Some(-2.0), "-2".to_string())
+        );
+
+        let physical = SignalValue::from(-1.0);
+        assert_eq!(physical.to_string(), "-1");
+        assert_eq!(signal.decode_raw_value(&data[..1]), None);
+
+        let label = SignalValue::from("Off".to_string());
+        assert_eq!(
+            (label.as_f64(), label.to_string()),
+            (None, "Off".to_string())
+        );
+        assert_eq!(SignalValue::from(3_i64), SignalValue::Raw(3));
+    }
+
+    #[test]
+    fn test_signal_decode_raw_signed_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.value_type = ValueType::Signed;
+        signal.start_bit = 2;
+        signal.size = 3;
+        let data = [0b0001_1100];
+        assert_eq!(signal.decode_raw(&data), Some(0b111));
+        assert_eq!(signal.decode_raw_signed(&data), Some(-1));
+        assert_eq!(signal.decode_raw_signed(&[0b0000_1100]), Some(3));
+        assert_eq!(signal.decode_raw_signed(&[0b0001_0000]), Some(-4));
+        assert_eq!(
+            signal.decode(&data, SignalExtendedValueType::Integer),
+            Some(-1.0)
+        );
+
+        signal.value_type = ValueType::Unsigned;
+        assert_eq!(signal.decode_raw_signed(&data), Some(7));
+    }
+
+    #[test]
+    fn test_signal_decode_raw_signed_02() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.value_type = ValueType::Signed;
+        signal.start_bit = 0;
+        signal.size = 24;
+        let data = (-100_000_i32).to_le_bytes();
+        assert_eq!(signal.decode_raw(&data), Some(0xFE_7960));
+        assert_eq!(signal.decode_raw_signed(&data), Some(-100_000));
+        assert_eq!(
+            signal.decode_raw_signed(&8_388_607_i32.to_le_bytes()),
+            Some(8_388_607)
+        );
+        assert_eq!(
+            signal.decode_raw_signed(&8_388_608_i32.to_le_bytes()),
+            Some(-8_388_608)
+        );
+    }
+
+    #[test]
+    fn test_signal_decode_fd_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.start_bit = 203;
+        signal.size = 40;
+        let mut data = [0u8; 64];
+        data[25..30].copy_from_slice(&[0xD0, 0xC4, 0xB3, 0xA2, 0x91]);
+        assert_eq!(signal.decode_raw(&data), Some(0x12_3456_789A));
+        assert_eq!(signal.decode_raw(&data[..29]), None);
+
+        let mut encoded = [0u8; 64];
+        signal
+            .encode(78_187_493_530.0, &mut encoded, EncodeMode::Error)
+            .unwrap();
+        assert_eq!(encoded, data);
+    }
+
+    #[test]
+    fn test_signal_decode_fd_02() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.start_bit = 203;
+        signal.size = 40;
+        signal.byte_order = ByteOrder::BigEndian;
+        let mut data = [0u8; 64];
+        data[25..31].copy_from_slice(&[0x01, 0x23, 0x45, 0x67, 0x89, 0xA0]);
+        assert_eq!(signal.decode_raw(&data), Some(0x12_3456_789A));
+
+        signal.size = 64;
+        signal.start_bit = 7;
+        signal.value_type = ValueType::Signed;
+        let data = (-2_i64).to_be_bytes();
+        assert_eq!(signal.decode_raw(&data), Some(u64::MAX - 1));
+        assert_eq!(
+            signal.decode(&data, SignalExtendedValueType::Integer),
+            Some(-2.0)
+        );
+    }
+
+    #[test]
+    fn test_signal_decode_float_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.start_bit = 0;
+        signal.size = 32;
+        // 0x41480000 is 12.5_f32
+        let data = [0x00, 0x00, 0x48, 0x41];
+        assert_eq!(
+            signal.decode(&data, SignalExtendedValueType::Float),
+            Some(12.5)
+        );
+        assert_eq!(
+            signal.decode(&data, SignalExtendedValueType::Integer),
+            Some(1_095_237_632.0)
+        );
+        assert_eq!(signal.decode(&data, SignalExtendedValueType::Double), None);
+    }
+
+    #[test]
+    fn test_signal_decode_double_01() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.start_bit = 0;
+        signal.size = 64;
+        signal.factor = 2.0;
+        let data = (-1.25_f64).to_le_bytes();
+        assert_eq!(
+            signal.decode(&data, SignalExtendedValueType::Double),
+            Some(-2.5)
+        );
+    }
+
+    #[test]
+    fn test_signal_decode_float_02() {
+        let mut signal = signal_u8(0.0, 0.0);
+        signal.start_bit = 0;
+        signal.size = 32;
+        signal.factor = 0.5;
+        signal.offset = 10.0;
+        let data = (-25.0_f32).to_le_bytes();
+        assert_eq!(
+            signal.decode(&data, SignalExtendedValueType::Float),
+            Some(-2.5)
+        );
+    }
 }
@@ -0,0 +1,157 @@
+use std::fmt;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::line_ending;
+use nom::combinator::{map, opt};
+use nom::multi::many0;
+use nom::{IResult, Parser};
+
+use super::common_parsers::{multispacey, parser_message_id, parser_signal_name, spacey};
+use super::error::DbcParseError;
+
+/// ```text
+/// signal_extended_value_type = '0' | '1' | '2' | '3' ;
+/// (* 0=signed or unsigned integer, 1=32-bit IEEE-float, 2=64-bit IEEE-double *)
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignalExtendedValueType {
+    #[default]
+    Integer,
+    Float,
+    Double,
+}
+
+impl fmt::Display for SignalExtendedValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignalExtendedValueType::Integer => write!(f, "0"),
+            SignalExtendedValueType::Float => write!(f, "1"),
+            SignalExtendedValueType::Double => write!(f, "2"),
+        }
+    }
+}
+
+/// Signal extended value type list section, marks signals whose raw bits are an
+/// IEEE float or double instead of an integer.
+///
+/// ```text
+/// signal_extended_value_type_list = 'SIG_VALTYPE_' message_id signal_name
+/// signal_extended_value_type ';' ;
+/// ```
+///
+/// example:
+///
+/// ```text
+/// SIG_VALTYPE_ 1024 Temperature : 1;
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalExtendedValueTypeList {
+    pub message_id: u32,
+    pub signal_name: String,
+    pub value_type: SignalExtendedValueType,
+}
+
+impl fmt::Display for SignalExtendedValueTypeList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SIG_VALTYPE_ {} {} : {};",
+            self.message_id, self.signal_name, self.value_type
+        )
+    }
+}
+
+fn parser_signal_extended_value_type_value(
+    input: &str,
+) -> IResult<&str, SignalExtendedValueType, DbcParseError> {
+    alt((
+        map(tag("0"), |_| SignalExtendedValueType::Integer),
+        map(tag("1"), |_| SignalExtendedValueType::Float),
+        map(tag("2"), |_| SignalExtendedValueType::Double),
+    ))
+    .parse(input)
+}
+
+pub fn parser_signal_extended_value_type(
+    input: &str,
+) -> IResult<&str, SignalExtendedValueTypeList, DbcParseError> {
+    let res = map(
+        (
+            multispacey(tag("SIG_VALTYPE_")),
+            spacey(parser_message_id),
+            spacey(parser_signal_name),
+            spacey(opt(tag(":"))),
+            spacey(parser_signal_extended_value_type_value),
+            spacey(tag(";")),
+            many0(line_ending),
+        ),
+        |(_, message_id, signal_name, _, value_type, _, _)| SignalExtendedValueTypeList {
+            message_id,
+            signal_name: signal_name.to_string(),
+            value_type,
+        },
+    )
+    .parse(input);
+
+    match res {
+        Ok((remain, val)) => {
+            log::info!("parse signal extended value type: {val:?}");
+            Ok((remain, val))
+        }
+        Err(e) => {
+            log::trace!("parse signal extended value type failed, e = {e:?}");
+            Err(nom::Err::Error(DbcParseError::BadSignalExtendedValueType))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signal_extended_value_type_string_01() {
+        assert_eq!(
+            SignalExtendedValueTypeList {
+                message_id: 1024,
+                signal_name: "Temperature".to_string(),
+                value_type: SignalExtendedValueType::Float,
+            }
+            .to_string(),
+            "SIG_VALTYPE_ 1024 Temperature : 1;"
+        );
+    }
+
+    #[test]
+    fn test_parser_signal_extended_value_type_01() {
+        assert_eq!(
+            parser_signal_extended_value_type("SIG_VALTYPE_ 1024 Temperature : 1;"),
+            Ok((
+                "",
+                SignalExtendedValueTypeList {
+                    message_id: 1024,
+                    signal_name: "Temperature".to_string(),
+                    value_type: SignalExtendedValueType::Float,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parser_signal_extended_value_type_02() {
+        assert_eq!(
+            parser_signal_extended_value_type("SIG_VALTYPE_ 1024 Pressure 2;"),
+            Ok((
+                "",
+                SignalExtendedValueTypeList {
+                    message_id: 1024,
+                    signal_name: "Pressure".to_string(),
+                    value_type: SignalExtendedValueType::Double,
+                }
+            ))
+        );
+    }
+}
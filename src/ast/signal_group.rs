@@ -0,0 +1,127 @@
+use std::fmt;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{line_ending, space1};
+use nom::combinator::map;
+use nom::multi::{many0, separated_list0};
+use nom::{IResult, Parser};
+
+use super::common_parsers::{
+    dbc_identifier, multispacey, parser_message_id, parser_signal_name, spacey, unsigned_integer,
+};
+use super::error::DbcParseError;
+
+/// Signal groups define a group of signals within a message, e.g. to apply end-to-end
+/// protection to them as a unit.
+///
+/// ```text
+/// signal_groups = 'SIG_GROUP_' message_id signal_group_name repetitions ':' { signal_name } ';' ;
+/// ```
+///
+/// example:
+///
+/// ```text
+/// SIG_GROUP_ 256 E2E_Group 2 : Counter Checksum Speed;
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalGroup {
+    pub message_id: u32,
+    pub signal_group_name: String,
+    pub repetitions: u32,
+    pub signal_names: Vec<String>,
+}
+
+impl fmt::Display for SignalGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SIG_GROUP_ {} {} {} :",
+            self.message_id, self.signal_group_name, self.repetitions
+        )?;
+        for signal_name in &self.signal_names {
+            write!(f, " {signal_name}")?;
+        }
+        write!(f, ";")
+    }
+}
+
+pub fn parser_signal_group(input: &str) -> IResult<&str, SignalGroup, DbcParseError> {
+    let res = map(
+        (
+            multispacey(tag("SIG_GROUP_")),
+            spacey(parser_message_id),
+            spacey(dbc_identifier),
+            spacey(unsigned_integer),
+            spacey(tag(":")),
+            separated_list0(alt((spacey(tag(",")), space1)), parser_signal_name),
+            spacey(tag(";")),
+            many0(line_ending),
+        ),
+        |(_, message_id, signal_group_name, repetitions, _, signal_names, _, _)| SignalGroup {
+            message_id,
+            signal_group_name: signal_group_name.to_string(),
+            repetitions,
+            signal_names: signal_names.into_iter().map(String::from).collect(),
+        },
+    )
+    .parse(input);
+
+    match res {
+        Ok((remain, val)) => {
+            log::info!("parse signal group: {val:?}");
+            Ok((remain, val))
+        }
+        Err(e) => {
+            log::trace!("parse signal group failed, e = {e:?}");
+            Err(nom::Err::Error(DbcParseError::BadSignalGroup))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parser_signal_group_01() {
+        let input = "SIG_GROUP_ 256 E2E_Group 2 : Counter Checksum Speed;";
+        let (remain, group) = parser_signal_group(input).unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(
+            group,
+            SignalGroup {
+                message_id: 256,
+                signal_group_name: "E2E_Group".to_string(),
+                repetitions: 2,
+                signal_names: vec![
+                    "Counter".to_string(),
+                    "Checksum".to_string(),
+                    "Speed".to_string(),
+                ],
+            }
+        );
+        assert_eq!(group.to_string(), input);
+    }
+
+    #[test]
+    fn test_parser_signal_group_02() {
+        assert_eq!(
+            parser_signal_group("SIG_GROUP_ 1 Empty 1 : ;\n"),
+            Ok((
+                "",
+                SignalGroup {
+                    message_id: 1,
+                    signal_group_name: "Empty".to_string(),
+                    repetitions: 1,
+                    signal_names: vec![],
+                }
+            ))
+        );
+        assert_eq!(
+            parser_signal_group("SIG_GROUP_ 1 Broken : Speed;"),
+            Err(nom::Err::Error(DbcParseError::BadSignalGroup))
+        );
+    }
+}
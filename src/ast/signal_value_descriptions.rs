@@ -104,6 +104,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parser_signal_value_descriptions_02() {
+        let (remain, descriptions) = parser_signal_value_descriptions("VAL_ 123 Sig ;").unwrap();
+        assert_eq!(remain, "");
+        assert_eq!(
+            descriptions,
+            SignalValueDescriptions {
+                message_id: 123,
+                signal_name: "Sig".to_string(),
+                value_descriptions: ValueDescriptions { values: vec![] },
+            }
+        );
+        assert_eq!(descriptions.to_string(), "VAL_ 123 Sig;");
+        assert_eq!(
+            parser_signal_value_descriptions(&descriptions.to_string()),
+            Ok(("", descriptions))
+        );
+    }
+
     #[test]
     fn test_signal_value_descriptions_string_01() {
         assert_eq!(
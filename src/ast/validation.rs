@@ -0,0 +1,392 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use super::attribute_value::ObjectAttributeValue;
+use super::network_ast::NetworkAst;
+use super::signal_extended_value_type::SignalExtendedValueType;
+
+/// A defect found by [`NetworkAst::validate`]. The network still parsed, but tools may
+/// misbehave on it.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ValidationIssue {
+    /// More than one `BO_` uses the same message ID.
+    DuplicateMessageId { id: u32 },
+    /// A standard (11-bit) message ID is above `0x7FF`.
+    StandardIdOutOfRange { id: u32 },
+    /// A section is used but its keyword is not declared in `NS_`.
+    UndeclaredSymbol { symbol: &'static str },
+    /// A multiplexed signal's `m<n>` value is outside every `SG_MUL_VAL_` range given
+    /// for it.
+    MultiplexerValueOutOfRange {
+        message_id: u32,
+        signal_name: String,
+        value: u32,
+    },
+    /// A signal's declared `[min|max]` reaches beyond the values its bit width, factor and
+    /// offset can represent, see
+    /// [`Signal::computed_range`](super::signal::Signal::computed_range).
+    RangeNotRepresentable {
+        message_id: u32,
+        signal_name: String,
+        declared: (f64, f64),
+        representable: (f64, f64),
+    },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::DuplicateMessageId { id } => {
+                write!(f, "message id {id} is used by more than one message")
+            }
+            ValidationIssue::StandardIdOutOfRange { id } => {
+                write!(f, "message id {id} does not fit in 11 bits")
+            }
+            ValidationIssue::UndeclaredSymbol { symbol } => {
+                write!(f, "{symbol} is used but not declared in NS_")
+            }
+            ValidationIssue::MultiplexerValueOutOfRange {
+                message_id,
+                signal_name,
+                value,
+            } => write!(
+                f,
+                "signal {signal_name} of message {message_id} is multiplexed by value {value}, \
+                 which is outside its SG_MUL_VAL_ ranges"
+            ),
+            ValidationIssue::RangeNotRepresentable {
+                message_id,
+                signal_name,
+                declared,
+                representable,
+            } => write!(
+                f,
+                "signal {signal_name} of message {message_id} declares range [{}|{}], \
+                 but can only represent [{}|{}]",
+                declared.0, declared.1, representable.0, representable.1
+            ),
+        }
+    }
+}
+
+impl NetworkAst {
+    /// Check the network for defects the parser accepts.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = vec![];
+        issues.extend(
+            self.duplicate_message_ids()
+                .into_iter()
+                .map(|id| ValidationIssue::DuplicateMessageId { id }),
+        );
+        issues.extend(
+            self.messages
+                .iter()
+                .filter(|m| !m.header.has_valid_id())
+                .map(|m| ValidationIssue::StandardIdOutOfRange { id: m.header.id }),
+        );
+        issues.extend(
+            self.used_symbols()
+                .into_iter()
+                .filter(|symbol| !self.declares_symbol(symbol))
+                .map(|symbol| ValidationIssue::UndeclaredSymbol { symbol }),
+        );
+        issues.extend(self.multiplexer_values_out_of_range());
+        issues.extend(self.ranges_not_representable());
+        issues
+    }
+
+    /// Whether `symbol` (e.g. `BA_DEF_`) is declared in `NS_`.
+    pub fn declares_symbol(&self, symbol: &str) -> bool {
+        self.new_symbols.supports_symbol(symbol)
+    }
+
+    /// Keywords of the optional sections the network uses.
+    fn used_symbols(&self) -> Vec<&'static str> {
+        let sections = [
+            (
+                "VAL_TABLE_",
+                self.value_tables.as_ref().is_some_and(|v| !v.is_empty()),
+            ),
+            ("BO_TX_BU_", !self.message_transmitters.is_empty()),
+            ("ENVVAR_DATA_", !self.env_vars_data.is_empty()),
+            ("CM_", !self.comments.is_empty()),
+            ("BA_DEF_", !self.attribute_definitions.is_empty()),
+            ("BA_DEF_DEF_", !self.attribute_defaults.is_empty()),
+            (
+                "BA_",
+                self.attribute_values.iter().any(|v| !v.is_relation()),
+            ),
+            (
+                "BA_REL_",
+                self.attribute_values
+                    .iter()
+                    .any(ObjectAttributeValue::is_relation),
+            ),
+            (
+                "VAL_",
+                !self.signal_value_descriptions.is_empty()
+                    || !self.env_var_value_descriptions.is_empty(),
+            ),
+            ("SIG_GROUP_", !self.signal_groups.is_empty()),
+            ("SIG_VALTYPE_", !self.signal_extended_value_types.is_empty()),
+            ("SG_MUL_VAL_", !self.extended_multiplexings.is_empty()),
+        ];
+        sections
+            .into_iter()
+            .filter_map(|(symbol, used)| used.then_some(symbol))
+            .collect()
+    }
+
+    /// Multiplexed signals whose `m<n>` value none of their `SG_MUL_VAL_` ranges cover.
+    /// Signals without `SG_MUL_VAL_` entries are not checked.
+    fn multiplexer_values_out_of_range(&self) -> Vec<ValidationIssue> {
+        let mut issues = vec![];
+        for message in &self.messages {
+            for signal in &message.signals {
+                let Some(value) = signal
+                    .multiplexer
+                    .as_ref()
+                    .and_then(|m| m.multiplexer_signal)
+                else {
+                    continue;
+                };
+                let mut ranges = self
+                    .extended_multiplexings
+                    .iter()
+                    .filter(|m| m.message_id == message.header.id && m.signal_name == signal.name)
+                    .flat_map(|m| &m.value_ranges)
+                    .peekable();
+                if ranges.peek().is_some() && !ranges.any(|r| r.contains(u64::from(value))) {
+                    issues.push(ValidationIssue::MultiplexerValueOutOfRange {
+                        message_id: message.header.id,
+                        signal_name: signal.name.clone(),
+                        value,
+                    });
+                }
+            }
+        }
+        issues
+    }
+
+    /// Integer signals whose declared `[min|max]` exceeds the range their raw values can
+    /// map to. Half a scaling step of slack is allowed for bounds rounded when written.
+    /// Signals without a range or with `[0|0]`, and float signals, are not checked.
+    fn ranges_not_representable(&self) -> Vec<ValidationIssue> {
+        let mut issues = vec![];
+        for message in &self.messages {
+            for signal in &message.signals {
+                let (Some(min), Some(max)) = (signal.min, signal.max) else {
+                    continue;
+                };
+                if (min == 0.0 && max == 0.0)
+                    || self.signal_extended_value_type(message.header.id, &signal.name)
+                        != SignalExtendedValueType::Integer
+                {
+                    continue;
+                }
+                let (low, high) = signal.computed_range();
+                let slack = signal.factor.abs() / 2.0;
+                if min < low - slack || max > high + slack {
+                    issues.push(ValidationIssue::RangeNotRepresentable {
+                        message_id: message.header.id,
+                        signal_name: signal.name.clone(),
+                        declared: (min, max),
+                        representable: (low, high),
+                    });
+                }
+            }
+        }
+        issues
+    }
+
+    /// Message IDs used by more than one message, in order of their second use.
+    pub(crate) fn duplicate_message_ids(&self) -> Vec<u32> {
+        let mut seen = HashSet::new();
+        let mut duplicates = vec![];
+        for message in &self.messages {
+            let id = message.header.id;
+            if !seen.insert(id) && !duplicates.contains(&id) {
+                duplicates.push(id);
+            }
+        }
+        duplicates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::network_ast::parse_dbc;
+
+    #[test]
+    fn test_validate_duplicate_message_id_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+
+BO_ 200 Second: 8 ECU
+
+BO_ 100 Third: 8 ECU
+"#,
+        )
+        .unwrap();
+        let issues = network.validate();
+        assert_eq!(
+            issues,
+            vec![ValidationIssue::DuplicateMessageId { id: 100 }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "message id 100 is used by more than one message"
+        );
+    }
+
+    #[test]
+    fn test_validate_standard_id_out_of_range_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 0 First: 8 ECU
+
+BO_ 2048 Second: 8 ECU
+
+BO_ 2147485697 Extended: 8 ECU
+"#,
+        )
+        .unwrap();
+        assert_eq!(network.can_ids(), vec![0, 2048, 2049]);
+        let issues = network.validate();
+        assert_eq!(
+            issues,
+            vec![ValidationIssue::StandardIdOutOfRange { id: 2048 }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "message id 2048 does not fit in 11 bits"
+        );
+    }
+
+    #[test]
+    fn test_validate_undeclared_symbol_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+    BA_
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 First: 8 ECU
+
+CM_ BO_ 100 "Not declared in NS_";
+"#,
+        )
+        .unwrap();
+        assert!(network.declares_symbol("BA_"));
+        assert!(!network.declares_symbol("CM_"));
+        let issues = network.validate();
+        assert_eq!(
+            issues,
+            vec![ValidationIssue::UndeclaredSymbol { symbol: "CM_" }]
+        );
+        assert_eq!(issues[0].to_string(), "CM_ is used but not declared in NS_");
+    }
+
+    #[test]
+    fn test_validate_multiplexer_value_out_of_range_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+    SG_MUL_VAL_
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Muxed: 8 ECU
+ SG_ Mux_1 M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Covered m3 : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Uncovered m4 : 16|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Flat m5 : 24|8@1+ (1,0) [0|0] "" Vector__XXX
+
+SG_MUL_VAL_ 100 Covered Mux_1 1-3;
+SG_MUL_VAL_ 100 Uncovered Mux_1 1-3, 5-6;
+"#,
+        )
+        .unwrap();
+        let issues = network.validate();
+        assert_eq!(
+            issues,
+            vec![ValidationIssue::MultiplexerValueOutOfRange {
+                message_id: 100,
+                signal_name: "Uncovered".to_string(),
+                value: 4,
+            }]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "signal Uncovered of message 100 is multiplexed by value 4, \
+             which is outside its SG_MUL_VAL_ ranges"
+        );
+    }
+
+    #[test]
+    fn test_validate_range_not_representable_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: ECU
+
+BO_ 100 Engine: 8 ECU
+ SG_ Level : 0|8@1+ (1,0) [0|1000] "" Vector__XXX
+ SG_ Yaw_Rate : 8|16@1+ (0.005,-163.84) [-163.84|163.83] "deg/s" Vector__XXX
+ SG_ Unset : 24|8@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Rounded : 32|8@1+ (0.3,0) [0|76.6] "" Vector__XXX
+ SG_ Offset : 40|8@1- (1,-100) [-300|0] "" Vector__XXX
+"#,
+        )
+        .unwrap();
+        let issues = network.validate();
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue::RangeNotRepresentable {
+                    message_id: 100,
+                    signal_name: "Level".to_string(),
+                    declared: (0.0, 1000.0),
+                    representable: (0.0, 255.0),
+                },
+                ValidationIssue::RangeNotRepresentable {
+                    message_id: 100,
+                    signal_name: "Offset".to_string(),
+                    declared: (-300.0, 0.0),
+                    representable: (-228.0, 27.0),
+                },
+            ]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "signal Level of message 100 declares range [0|1000], but can only represent [0|255]"
+        );
+    }
+}
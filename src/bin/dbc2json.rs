@@ -19,13 +19,21 @@ struct Opt {
 
     /// Output json file
     output: PathBuf,
+
+    /// Write the json on a single line instead of pretty-printing it
+    #[arg(long)]
+    compact: bool,
 }
 
 fn main() -> Result<()> {
     env_logger::init();
     let opt = Opt::parse();
     let network_ast = parser_dbc_file(opt.input, &opt.encoding)?;
-    let network_ast_json = serde_json::to_string_pretty(&network_ast)?;
+    let network_ast_json = if opt.compact {
+        serde_json::to_string(&network_ast)?
+    } else {
+        serde_json::to_string_pretty(&network_ast)?
+    };
     std::fs::write(opt.output, network_ast_json)?;
     Ok(())
 }
@@ -22,7 +22,7 @@ fn main() -> Result<()> {
     env_logger::init();
     let opt = Opt::parse();
     let network_ast = parser_dbc_file(&opt.input, &opt.encoding)?;
-    let output_data = format!("{network_ast}");
+    let output_data = network_ast.to_dbc();
     std::fs::write(opt.input, output_data)?;
     Ok(())
 }
@@ -19,7 +19,7 @@ fn main() -> Result<()> {
     let opt = Opt::parse();
     let input_data = std::fs::read_to_string(opt.input)?;
     let network_ast: NetworkAst = serde_json::from_str(&input_data)?;
-    let output_data = format!("{network_ast}");
+    let output_data = network_ast.to_dbc();
     std::fs::write(opt.output, output_data)?;
     Ok(())
 }
@@ -0,0 +1,90 @@
+use crate::ast::network_ast::NetworkAst;
+use crate::error::DbcError;
+
+impl NetworkAst {
+    /// Serialize the network into a compact binary form, e.g. to cache a parsed file.
+    ///
+    /// bincode is not self-describing, so every field is written in declaration order,
+    /// including the empty lists the serde derive skips.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let fields = (
+            (
+                &self.version,
+                &self.new_symbols,
+                &self.bit_timing,
+                &self.nodes,
+                &self.value_tables,
+                &self.messages,
+                &self.message_transmitters,
+                &self.env_vars,
+                &self.env_vars_data,
+                &self.comments,
+            ),
+            (
+                &self.attribute_definitions,
+                &self.attribute_defaults,
+                &self.attribute_values,
+                &self.signal_type_attribute_values,
+                &self.signal_value_descriptions,
+                &self.env_var_value_descriptions,
+                &self.signal_type_value_descriptions,
+                &self.signal_groups,
+                &self.signal_extended_value_types,
+                &self.extended_multiplexings,
+            ),
+        );
+        bincode::serialize(&fields).expect("serializing to memory cannot fail")
+    }
+
+    /// Deserialize a network written by [`NetworkAst::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<NetworkAst, DbcError> {
+        let (
+            (
+                version,
+                new_symbols,
+                bit_timing,
+                nodes,
+                value_tables,
+                messages,
+                message_transmitters,
+                env_vars,
+                env_vars_data,
+                comments,
+            ),
+            (
+                attribute_definitions,
+                attribute_defaults,
+                attribute_values,
+                signal_type_attribute_values,
+                signal_value_descriptions,
+                env_var_value_descriptions,
+                signal_type_value_descriptions,
+                signal_groups,
+                signal_extended_value_types,
+                extended_multiplexings,
+            ),
+        ) = bincode::deserialize(bytes).map_err(|_| DbcError::InvalidBytes)?;
+        Ok(NetworkAst {
+            version,
+            new_symbols,
+            bit_timing,
+            nodes,
+            value_tables,
+            messages,
+            message_transmitters,
+            env_vars,
+            env_vars_data,
+            comments,
+            attribute_definitions,
+            attribute_defaults,
+            attribute_values,
+            signal_type_attribute_values,
+            signal_value_descriptions,
+            env_var_value_descriptions,
+            signal_type_value_descriptions,
+            signal_groups,
+            signal_extended_value_types,
+            extended_multiplexings,
+        })
+    }
+}
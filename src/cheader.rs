@@ -0,0 +1,287 @@
+use std::fmt::Write as _;
+
+use crate::ast::message::Message;
+use crate::ast::network_ast::NetworkAst;
+use crate::ast::signal::{ByteOrder, Signal, ValueType};
+use crate::ast::signal_extended_value_type::SignalExtendedValueType;
+
+const HELPERS: &str = r"static inline uint64_t dbc_get_bits(const uint8_t *data, uint32_t start, uint32_t size, int big_endian)
+{
+    uint64_t value = 0;
+    uint32_t bit = start;
+    for (uint32_t i = 0; i < size; i++) {
+        uint64_t b = (data[bit / 8] >> (bit % 8)) & 1u;
+        if (big_endian) {
+            value = (value << 1) | b;
+            bit = (bit % 8 == 0) ? bit + 15 : bit - 1;
+        } else {
+            value |= b << i;
+            bit++;
+        }
+    }
+    return value;
+}
+
+static inline void dbc_set_bits(uint8_t *data, uint32_t start, uint32_t size, int big_endian, uint64_t value)
+{
+    uint32_t bit = start;
+    for (uint32_t i = 0; i < size; i++) {
+        uint64_t b = big_endian ? (value >> (size - 1 - i)) & 1u : (value >> i) & 1u;
+        data[bit / 8] = (uint8_t)((data[bit / 8] & ~(1u << (bit % 8))) | (b << (bit % 8)));
+        if (big_endian) {
+            bit = (bit % 8 == 0) ? bit + 15 : bit - 1;
+        } else {
+            bit++;
+        }
+    }
+}
+
+static inline int64_t dbc_sign_extend(uint64_t value, uint32_t size)
+{
+    uint64_t sign = 1ull << (size - 1);
+    return (int64_t)((value ^ sign) - sign);
+}
+
+static inline float dbc_float_from_bits(uint32_t bits)
+{
+    float value;
+    memcpy(&value, &bits, sizeof(value));
+    return value;
+}
+
+static inline uint32_t dbc_float_to_bits(float value)
+{
+    uint32_t bits;
+    memcpy(&bits, &value, sizeof(bits));
+    return bits;
+}
+
+static inline double dbc_double_from_bits(uint64_t bits)
+{
+    double value;
+    memcpy(&value, &bits, sizeof(value));
+    return value;
+}
+
+static inline uint64_t dbc_double_to_bits(double value)
+{
+    uint64_t bits;
+    memcpy(&bits, &value, sizeof(bits));
+    return bits;
+}
+";
+
+/// Generate a C header with a packed struct of raw signal values per DBC message.
+///
+/// Every signal gets `_GET`/`_SET` macros reading and writing its bits in a payload,
+/// honoring its byte order and sign, and `_DECODE`/`_ENCODE` macros converting between
+/// raw and physical values. Every message gets `_unpack`/`_pack` functions filling its
+/// struct from a payload and back.
+pub fn to_c_header(network: &NetworkAst) -> String {
+    let mut out = String::from(
+        "/* Generated from a DBC file. */\n\n#ifndef DBC_H\n#define DBC_H\n\n\
+         #include <stdint.h>\n#include <string.h>\n\n",
+    );
+    out.push_str(HELPERS);
+    for message in &network.messages {
+        out.push('\n');
+        write_message(&mut out, network, message);
+    }
+    out.push_str("\n#endif /* DBC_H */\n");
+    out
+}
+
+fn write_message(out: &mut String, network: &NetworkAst, message: &Message) {
+    let name = snake_case(&message.header.name);
+    let prefix = name.to_ascii_uppercase();
+    let _ = writeln!(
+        out,
+        "/* BO_ {} {} */",
+        message.header.id, message.header.name
+    );
+    let _ = writeln!(out, "#define {prefix}_FRAME_ID ({}u)", message.header.id);
+    let _ = writeln!(out, "#define {prefix}_LENGTH ({}u)", message.header.size);
+
+    let signals: Vec<(String, &Signal, SignalExtendedValueType)> = message
+        .signals
+        .iter()
+        .map(|signal| {
+            let value_type = network.signal_extended_value_type(message.header.id, &signal.name);
+            (snake_case(&signal.name), signal, value_type)
+        })
+        .collect();
+
+    for (field, signal, value_type) in &signals {
+        let macro_name = format!("{prefix}_{}", field.to_ascii_uppercase());
+        let bits = format!(
+            "(data), {}u, {}u, {}",
+            signal.start_bit,
+            signal.size,
+            u8::from(signal.byte_order == ByteOrder::BigEndian)
+        );
+        let c_type = c_type(signal, *value_type);
+        let (get, set) = match value_type {
+            SignalExtendedValueType::Float => (
+                format!("dbc_float_from_bits((uint32_t)dbc_get_bits({bits}))"),
+                "(uint64_t)dbc_float_to_bits((float)(value))".to_string(),
+            ),
+            SignalExtendedValueType::Double => (
+                format!("dbc_double_from_bits(dbc_get_bits({bits}))"),
+                "dbc_double_to_bits((double)(value))".to_string(),
+            ),
+            SignalExtendedValueType::Integer if signal.value_type == ValueType::Signed => (
+                format!(
+                    "({c_type})dbc_sign_extend(dbc_get_bits({bits}), {}u)",
+                    signal.size
+                ),
+                "(uint64_t)(value)".to_string(),
+            ),
+            SignalExtendedValueType::Integer => (
+                format!("({c_type})dbc_get_bits({bits})"),
+                "(uint64_t)(value)".to_string(),
+            ),
+        };
+        out.push('\n');
+        let _ = writeln!(out, "#define {macro_name}_GET(data) ({get})");
+        let _ = writeln!(
+            out,
+            "#define {macro_name}_SET(data, value) dbc_set_bits({bits}, {set})"
+        );
+        let _ = writeln!(
+            out,
+            "#define {macro_name}_DECODE(raw) ((double)(raw) * {:?} + {:?})",
+            signal.factor, signal.offset
+        );
+        let _ = writeln!(
+            out,
+            "#define {macro_name}_ENCODE(phys) (((double)(phys) - {:?}) / {:?})",
+            signal.offset, signal.factor
+        );
+    }
+
+    let _ = writeln!(out, "\nstruct {name}_t {{");
+    for (field, signal, value_type) in &signals {
+        let _ = writeln!(out, "    {} {field};", c_type(signal, *value_type));
+    }
+    if signals.is_empty() {
+        out.push_str("    uint8_t reserved;\n");
+    }
+    out.push_str("} __attribute__((packed));\n");
+
+    let _ = writeln!(
+        out,
+        "\nstatic inline void {name}_unpack(struct {name}_t *dst, const uint8_t *src)\n{{"
+    );
+    if signals.is_empty() {
+        out.push_str("    (void)dst;\n    (void)src;\n");
+    }
+    for (field, _, _) in &signals {
+        let _ = writeln!(
+            out,
+            "    dst->{field} = {prefix}_{}_GET(src);",
+            field.to_ascii_uppercase()
+        );
+    }
+    out.push_str("}\n");
+
+    let _ = writeln!(
+        out,
+        "\nstatic inline void {name}_pack(uint8_t *dst, const struct {name}_t *src)\n{{"
+    );
+    let _ = writeln!(out, "    memset(dst, 0, {prefix}_LENGTH);");
+    if signals.is_empty() {
+        out.push_str("    (void)src;\n");
+    }
+    for (field, _, _) in &signals {
+        let _ = writeln!(
+            out,
+            "    {prefix}_{}_SET(dst, src->{field});",
+            field.to_ascii_uppercase()
+        );
+    }
+    out.push_str("}\n");
+}
+
+fn c_type(signal: &Signal, value_type: SignalExtendedValueType) -> &'static str {
+    match value_type {
+        SignalExtendedValueType::Float => return "float",
+        SignalExtendedValueType::Double => return "double",
+        SignalExtendedValueType::Integer => {}
+    }
+    let signed = signal.value_type == ValueType::Signed;
+    match (signal.size, signed) {
+        (0..=8, false) => "uint8_t",
+        (0..=8, true) => "int8_t",
+        (9..=16, false) => "uint16_t",
+        (9..=16, true) => "int16_t",
+        (17..=32, false) => "uint32_t",
+        (17..=32, true) => "int32_t",
+        (_, false) => "uint64_t",
+        (_, true) => "int64_t",
+    }
+}
+
+/// `EngineData` -> `engine_data`
+fn snake_case(s: &str) -> String {
+    let mut out = String::new();
+    let mut prev: Option<char> = None;
+    for c in s.chars() {
+        if c.is_ascii_uppercase()
+            && prev.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit())
+        {
+            out.push('_');
+        }
+        out.push(c.to_ascii_lowercase());
+        prev = Some(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::network_ast::parse_dbc;
+
+    #[test]
+    fn test_to_c_header_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Engine
+
+BO_ 100 EngineData: 8 Engine
+ SG_ Rpm : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+ SG_ Temperature : 23|8@0- (0.5,-40) [0|0] "degC" Vector__XXX
+ SG_ Pressure : 32|32@1+ (1,0) [0|0] "bar" Vector__XXX
+
+BO_ 200 Status: 1 Engine
+
+SIG_VALTYPE_ 100 Pressure : 1;
+"#,
+        )
+        .unwrap();
+        let header = to_c_header(&network);
+        assert!(header.starts_with("/* Generated from a DBC file. */\n\n#ifndef DBC_H\n"));
+        assert!(header.ends_with("#endif /* DBC_H */\n"));
+        assert!(header.contains("#define ENGINE_DATA_FRAME_ID (100u)\n"));
+        assert!(header.contains(
+            "struct engine_data_t {\n    uint16_t rpm;\n    int8_t temperature;\n    float pressure;\n} __attribute__((packed));\n"
+        ));
+        assert!(header.contains(
+            "#define ENGINE_DATA_RPM_GET(data) ((uint16_t)dbc_get_bits((data), 0u, 16u, 0))\n"
+        ));
+        assert!(header.contains(
+            "#define ENGINE_DATA_TEMPERATURE_GET(data) ((int8_t)dbc_sign_extend(dbc_get_bits((data), 23u, 8u, 1), 8u))\n"
+        ));
+        assert!(header.contains(
+            "#define ENGINE_DATA_TEMPERATURE_DECODE(raw) ((double)(raw) * 0.5 + -40.0)\n"
+        ));
+        assert!(header.contains("    ENGINE_DATA_PRESSURE_SET(dst, src->pressure);\n"));
+        assert!(header.contains("struct status_t {\n    uint8_t reserved;\n}"));
+        assert!(header.contains("static inline void status_unpack("));
+    }
+}
@@ -6,6 +6,7 @@ use std::str::from_utf8_mut;
 
 use encoding_rs::{CoderResult, Decoder, Encoder, Encoding, UTF_8, WINDOWS_1252};
 
+use crate::ast::network_ast::{parse_dbc, NetworkAst};
 use crate::error::DbcError;
 
 /// A helper function to decode cp1252 bytes, as DBC files are often encoded in cp1252.
@@ -18,6 +19,34 @@ pub fn decode_cp1252(bytes: &[u8]) -> Option<Cow<'_, str>> {
     }
 }
 
+/// Read a DBC file from any reader, decode it from `encoding_label` (e.g. `"windows-1252"`)
+/// and parse it.
+pub fn parse_dbc_reader(
+    mut reader: impl Read,
+    encoding_label: &str,
+) -> Result<NetworkAst, DbcError> {
+    let src_encoding = get_encoding(Some(encoding_label.to_string()))?;
+    let mut decoder = src_encoding.new_decoder();
+    let mut encoder = UTF_8.new_encoder();
+
+    let mut buf = Vec::new();
+    convert_via_utf8(&mut decoder, &mut encoder, &mut reader, &mut buf, true)?;
+    let content = String::from_utf8(buf).map_err(|_| DbcError::EncodingReadInputError)?;
+    parse_dbc(&content).map_err(DbcError::ParseError)
+}
+
+/// Decode DBC file content that is either UTF-8 or cp1252.
+///
+/// Content that is valid UTF-8 is used as is, since decoding it as cp1252 would turn
+/// e.g. `°` (`C2 B0`) into `Â°`. Anything else is decoded with [`decode_cp1252`].
+pub fn decode_utf8_or_cp1252(bytes: &[u8]) -> Option<Cow<'_, str>> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    match std::str::from_utf8(bytes) {
+        Ok(s) => Some(Cow::Borrowed(s)),
+        Err(_) => decode_cp1252(bytes),
+    }
+}
+
 pub fn utf8_to_gbk(src_data: &[u8]) -> Result<Vec<u8>, DbcError> {
     recode(src_data, "UTF-8", "GBK")
 }
@@ -148,3 +177,57 @@ pub fn convert_via_utf8(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const ABS_DBC: &[u8] = include_bytes!("../dbc/mytest/abs.dbc");
+
+    #[test]
+    fn test_decode_cp1252_01() {
+        assert_eq!(decode_cp1252(b"\xB0/s").as_deref(), Some("\u{B0}/s"));
+        assert_eq!(decode_cp1252(b"\x80").as_deref(), Some("\u{20AC}"));
+    }
+
+    #[test]
+    fn test_decode_utf8_or_cp1252_01() {
+        assert_eq!(decode_utf8_or_cp1252(b"\xB0/s").as_deref(), Some("°/s"));
+        assert_eq!(decode_utf8_or_cp1252(b"\xC2\xB0/s").as_deref(), Some("°/s"));
+        assert_eq!(
+            decode_utf8_or_cp1252(b"\xEF\xBB\xBF\xC2\xB0/s").as_deref(),
+            Some("°/s")
+        );
+        assert_eq!(decode_cp1252(b"\xC2\xB0/s").as_deref(), Some("Â°/s"));
+    }
+
+    #[test]
+    fn test_parse_dbc_reader_01() {
+        let expected = parse_dbc(std::str::from_utf8(ABS_DBC).unwrap()).unwrap();
+        assert_eq!(
+            parse_dbc_reader(Cursor::new(ABS_DBC), "utf-8"),
+            Ok(expected.clone())
+        );
+
+        let cp1252 = recode(ABS_DBC, "UTF-8", "windows-1252").unwrap();
+        assert_ne!(cp1252, ABS_DBC);
+        assert_eq!(
+            parse_dbc_reader(Cursor::new(cp1252), "windows-1252"),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_parse_dbc_reader_02() {
+        assert_eq!(
+            parse_dbc_reader(Cursor::new(ABS_DBC), "no-such-encoding"),
+            Err(DbcError::InvalidEncodingLabel("no-such-encoding".into()))
+        );
+        assert!(matches!(
+            parse_dbc_reader(Cursor::new(b"BO_ 1 Bad".as_slice()), "utf-8"),
+            Err(DbcError::ParseError(_))
+        ));
+    }
+}
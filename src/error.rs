@@ -11,4 +11,40 @@ pub enum DbcError {
     EncodingReadInputError,
     #[error("encoding writing output error")]
     EncodingWriteOutputError,
+    #[error("invalid serialized network")]
+    InvalidBytes,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum EncodeError {
+    #[error("value {value} is out of range [{min}|{max}]")]
+    OutOfRange { value: f64, min: f64, max: f64 },
+    #[error("payload is too short for signal")]
+    PayloadTooShort,
+    #[error("signal bit range is invalid")]
+    BitRangeInvalid,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum DecodeError {
+    #[error("payload is too short for signal")]
+    PayloadTooShort,
+    #[error("signal bit range is invalid")]
+    BitRangeInvalid,
+    #[error("signal size does not match its float value type")]
+    FloatSizeMismatch,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum AttributeError {
+    #[error("attribute {0} is not defined for this object type")]
+    Undefined(String),
+    #[error("value does not match the type of attribute {0}")]
+    TypeMismatch(String),
+}
+
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum SignalLayoutError {
+    #[error("signal {signal} overlaps the bits of signal {existing}")]
+    Overlap { signal: String, existing: String },
 }
@@ -2,6 +2,17 @@ pub mod ast;
 pub mod error;
 pub mod network;
 pub mod node;
+pub mod signal_db;
 
+#[cfg(feature = "bincode")]
+pub mod bytes;
+#[cfg(feature = "cheader")]
+pub mod cheader;
 #[cfg(feature = "encoding")]
 pub mod encoding;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "proto")]
+pub mod proto;
+#[cfg(feature = "testutil")]
+pub mod testutil;
@@ -1,7 +1,12 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Write as _;
 
+use super::ast::comment::Comment;
+use super::ast::network_ast::NetworkAst;
 use super::node::Node;
 
+#[derive(PartialEq, Debug, Clone, Default)]
 pub struct Network {
     /// nodes (BU)
     ///
@@ -9,3 +14,100 @@ pub struct Network {
     /// - value: node
     pub nodes: HashMap<String, Node>,
 }
+
+impl Network {
+    /// One line per node, sorted by name, with the number of messages it transmits.
+    ///
+    /// ```text
+    /// ABS: 2 messages
+    /// Gateway: 0 messages
+    /// ```
+    pub fn summary(&self) -> String {
+        let mut nodes: Vec<&Node> = self.nodes.values().collect();
+        nodes.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut out = String::new();
+        for node in nodes {
+            let count = node.transmitted_messages.len();
+            let plural = if count == 1 { "" } else { "s" };
+            let _ = writeln!(out, "{}: {count} message{plural}", node.name);
+        }
+        out
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.summary())
+    }
+}
+
+impl From<&NetworkAst> for Network {
+    fn from(ast: &NetworkAst) -> Self {
+        let nodes = ast
+            .nodes
+            .0
+            .iter()
+            .map(|name| {
+                let comment = ast
+                    .comments
+                    .iter()
+                    .find_map(|comment| match comment {
+                        Comment::Node(c) if &c.node_name == name => Some(c.comment.0.clone()),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+                let transmitted_messages = ast
+                    .messages_from(name)
+                    .iter()
+                    .map(|message| message.header.id)
+                    .collect();
+                let node = Node {
+                    name: name.clone(),
+                    comment,
+                    transmitted_messages,
+                };
+                (name.clone(), node)
+            })
+            .collect();
+        Network { nodes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::network_ast::parse_dbc;
+
+    #[test]
+    fn test_network_summary_01() {
+        let ast = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Gateway ABS Engine
+
+BO_ 100 EngineData: 8 Engine
+
+BO_ 117 WheelSpeeds: 8 ABS
+
+BO_ 118 BrakeStatus: 8 ABS
+
+BO_TX_BU_ 117 : ABS,Gateway;
+
+CM_ BU_ ABS "Anti-lock braking";
+"#,
+        )
+        .unwrap();
+        let network = Network::from(&ast);
+        assert_eq!(network.nodes["ABS"].comment, "Anti-lock braking");
+        assert_eq!(network.nodes["ABS"].transmitted_messages, vec![117, 118]);
+        assert_eq!(
+            network.summary(),
+            "ABS: 2 messages\nEngine: 1 message\nGateway: 1 message\n"
+        );
+        assert_eq!(network.to_string(), network.summary());
+    }
+}
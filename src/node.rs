@@ -1,8 +1,12 @@
 /// Node (BU)
+#[derive(PartialEq, Debug, Clone, Default)]
 pub struct Node {
     /// node name
     pub name: String,
 
     /// comment (CM)
     pub comment: String,
+
+    /// IDs of the messages the node transmits (`BO_`, `BO_TX_BU_`)
+    pub transmitted_messages: Vec<u32>,
 }
@@ -0,0 +1,61 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use rayon::prelude::*;
+
+use crate::ast::network_ast::NetworkAst;
+use crate::encoding::parse_dbc_reader;
+use crate::error::DbcError;
+
+/// Parse DBC files in parallel, decoding each from `encoding_label`.
+///
+/// Every file gets its own result, in the order of `paths`, so one bad file doesn't stop
+/// the others. A file that can't be opened is reported as
+/// [`DbcError::EncodingReadInputError`].
+pub fn parse_many(
+    paths: &[PathBuf],
+    encoding_label: &str,
+) -> Vec<(PathBuf, Result<NetworkAst, DbcError>)> {
+    paths
+        .par_iter()
+        .map(|path| {
+            let result = match File::open(path) {
+                Ok(file) => parse_dbc_reader(BufReader::new(file), encoding_label),
+                Err(e) => {
+                    log::error!("Error opening {}, error = {e}", path.display());
+                    Err(DbcError::EncodingReadInputError)
+                }
+            };
+            (path.clone(), result)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_many_01() {
+        let mut paths: Vec<PathBuf> = std::fs::read_dir("dbc/mytest")
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "dbc"))
+            .collect();
+        paths.sort();
+        assert!(!paths.is_empty());
+        paths.push(PathBuf::from("dbc/mytest/missing.dbc"));
+
+        let results = parse_many(&paths, "utf-8");
+        assert_eq!(results.len(), paths.len());
+        for ((path, result), expected) in results.iter().zip(&paths) {
+            assert_eq!(path, expected);
+            if path.ends_with("missing.dbc") {
+                assert_eq!(result, &Err(DbcError::EncodingReadInputError));
+            } else {
+                assert!(result.is_ok(), "{}: {result:?}", path.display());
+            }
+        }
+    }
+}
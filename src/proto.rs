@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use crate::ast::message::Message;
+use crate::ast::network_ast::NetworkAst;
+use crate::ast::signal::{Signal, ValueType};
+use crate::ast::signal_extended_value_type::SignalExtendedValueType;
+use crate::ast::value_descriptions::ValueDescriptions;
+
+/// Generate a proto3 schema with a message per DBC message and a field per signal.
+///
+/// Fields use the type of the signal's physical value: `float`/`double` for IEEE
+/// signals and signals with a fractional factor or offset, an integer type otherwise.
+/// Signals with value descriptions (`VAL_`) get a nested enum instead.
+pub fn to_proto_schema(network: &NetworkAst) -> String {
+    let mut out = String::from("syntax = \"proto3\";\n\npackage dbc;\n");
+    for message in &network.messages {
+        out.push('\n');
+        write_message(&mut out, network, message);
+    }
+    out
+}
+
+fn write_message(out: &mut String, network: &NetworkAst, message: &Message) {
+    let _ = writeln!(out, "// BO_ {}", message.header.id);
+    let _ = writeln!(out, "message {} {{", message.header.name);
+    for (i, signal) in message.signals.iter().enumerate() {
+        let value_descriptions = network
+            .signal_value_descriptions
+            .iter()
+            .find(|v| v.message_id == message.header.id && v.signal_name == signal.name)
+            .map(|v| &v.value_descriptions)
+            .filter(|v| !v.values.is_empty());
+        let field_type = if let Some(value_descriptions) = value_descriptions {
+            let enum_name = format!("{}Values", signal.name);
+            write_enum(out, &enum_name, &signal.name, value_descriptions);
+            enum_name
+        } else {
+            let extended_value_type =
+                network.signal_extended_value_type(message.header.id, &signal.name);
+            scalar_type(signal, extended_value_type).to_string()
+        };
+        let _ = writeln!(out, "  {field_type} {} = {};", signal.name, i + 1);
+    }
+    out.push_str("}\n");
+}
+
+fn write_enum(out: &mut String, enum_name: &str, signal_name: &str, values: &ValueDescriptions) {
+    let prefix = constant_case(signal_name);
+    let mut names = HashSet::new();
+    let mut numbers = HashSet::new();
+    let _ = writeln!(out, "  enum {enum_name} {{");
+    // proto3 enums must start with a zero value
+    if !values.values.iter().any(|v| v.num == 0) {
+        let _ = writeln!(out, "    {prefix}_UNSPECIFIED = 0;");
+        names.insert(format!("{prefix}_UNSPECIFIED"));
+        numbers.insert(0);
+    }
+    let mut items: Vec<_> = values.values.iter().collect();
+    items.sort_by_key(|v| v.num != 0);
+    for item in items {
+        let Ok(num) = i32::try_from(item.num) else {
+            continue;
+        };
+        if !numbers.insert(num) {
+            continue;
+        }
+        let mut name = format!("{prefix}_{}", constant_case(&item.str.0));
+        if name.ends_with('_') || !names.insert(name.clone()) {
+            name = format!("{prefix}_{}", num.unsigned_abs());
+            names.insert(name.clone());
+        }
+        let _ = writeln!(out, "    {name} = {num};");
+    }
+    out.push_str("  }\n");
+}
+
+fn scalar_type(signal: &Signal, extended_value_type: SignalExtendedValueType) -> &'static str {
+    match extended_value_type {
+        SignalExtendedValueType::Float => return "float",
+        SignalExtendedValueType::Double => return "double",
+        SignalExtendedValueType::Integer => {}
+    }
+    if signal.factor.fract() != 0.0 || signal.offset.fract() != 0.0 {
+        return "double";
+    }
+    let (min, max) = signal.computed_range();
+    let wide = min < f64::from(i32::MIN) || max > f64::from(u32::MAX);
+    let unsigned = signal.value_type == ValueType::Unsigned && min >= 0.0;
+    match (unsigned, wide || (!unsigned && max > f64::from(i32::MAX))) {
+        (true, false) => "uint32",
+        (true, true) => "uint64",
+        (false, false) => "int32",
+        (false, true) => "int64",
+    }
+}
+
+/// `Gear Position` -> `GEAR_POSITION`
+fn constant_case(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_uppercase());
+        } else if !out.is_empty() && !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    out.trim_end_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::network_ast::parse_dbc;
+
+    #[test]
+    fn test_to_proto_schema_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Engine
+
+BO_ 100 EngineData: 8 Engine
+ SG_ Rpm : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+ SG_ Temperature : 16|8@1- (0.5,-40) [0|0] "degC" Vector__XXX
+ SG_ Gear : 24|3@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Pressure : 32|32@1+ (1,0) [0|0] "bar" Vector__XXX
+
+VAL_ 100 Gear 2 "Drive" 1 "Reverse" 0 "Park" ;
+
+SIG_VALTYPE_ 100 Pressure : 1;
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            to_proto_schema(&network),
+            r#"syntax = "proto3";
+
+package dbc;
+
+// BO_ 100
+message EngineData {
+  uint32 Rpm = 1;
+  double Temperature = 2;
+  enum GearValues {
+    GEAR_PARK = 0;
+    GEAR_DRIVE = 2;
+    GEAR_REVERSE = 1;
+  }
+  GearValues Gear = 3;
+  float Pressure = 4;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_to_proto_schema_02() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_:
+
+BO_ 1 Status: 1 Vector__XXX
+ SG_ Mode : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+VAL_ 1 Mode 2 "On / Off" 1 "Standby" ;
+"#,
+        )
+        .unwrap();
+        let schema = to_proto_schema(&network);
+        assert!(schema.contains("    MODE_UNSPECIFIED = 0;\n"));
+        assert!(schema.contains("    MODE_ON_OFF = 2;\n"));
+        assert!(schema.contains("  ModeValues Mode = 1;\n"));
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use crate::ast::network_ast::NetworkAst;
+use crate::ast::signal::{Signal, SignalValue};
+use crate::ast::signal_extended_value_type::SignalExtendedValueType;
+
+/// A signal with everything needed to decode it, denormalized from its message and the
+/// network's `SIG_VALTYPE_` and `VAL_` sections.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SignalEntry {
+    pub message_id: u32,
+    pub message_name: String,
+    /// message size in bytes
+    pub message_size: u32,
+    pub transmitter: String,
+    /// The signal as defined in `SG_`, with its bit layout, scaling and range.
+    pub signal: Signal,
+    pub extended_value_type: SignalExtendedValueType,
+    /// Value descriptions (`VAL_`) keyed by raw value.
+    pub value_labels: BTreeMap<i64, String>,
+}
+
+impl SignalEntry {
+    /// The signal's value in `data`: its value description as a [`SignalValue::Label`] if
+    /// the raw value has one, otherwise the [`SignalValue::Physical`] value, see
+    /// [`Signal::decode`].
+    pub fn decode(&self, data: &[u8]) -> Option<SignalValue> {
+        match self.label(data) {
+            Some(label) => Some(SignalValue::Label(label.to_string())),
+            None => self
+                .signal
+                .decode(data, self.extended_value_type)
+                .map(SignalValue::Physical),
+        }
+    }
+
+    /// Value description of the signal's raw value in `data`, if it has one.
+    pub fn label(&self, data: &[u8]) -> Option<&str> {
+        let raw = self.signal.decode_raw_signed(data)?;
+        self.value_labels.get(&raw).map(String::as_str)
+    }
+}
+
+/// Flat table of all signals of a network, one self-contained [`SignalEntry`] per signal.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct SignalDb {
+    pub entries: Vec<SignalEntry>,
+}
+
+impl SignalDb {
+    /// The signal named `signal_name` of the message `message_id`.
+    pub fn get(&self, message_id: u32, signal_name: &str) -> Option<&SignalEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.message_id == message_id && e.signal.name == signal_name)
+    }
+}
+
+impl NetworkAst {
+    /// Denormalize the network into a flat [`SignalDb`], in message and signal order.
+    pub fn signal_db(&self) -> SignalDb {
+        let mut entries = vec![];
+        for message in &self.messages {
+            for signal in &message.signals {
+                let value_labels = self
+                    .signal_value_descriptions
+                    .iter()
+                    .find(|v| v.message_id == message.header.id && v.signal_name == signal.name)
+                    .map(|v| {
+                        v.value_descriptions
+                            .values
+                            .iter()
+                            .map(|item| (item.num, item.str.0.clone()))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                entries.push(SignalEntry {
+                    message_id: message.header.id,
+                    message_name: message.header.name.clone(),
+                    message_size: message.header.size,
+                    transmitter: message.header.transmitter.clone(),
+                    signal: signal.clone(),
+                    extended_value_type: self
+                        .signal_extended_value_type(message.header.id, &signal.name),
+                    value_labels,
+                });
+            }
+        }
+        SignalDb { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::network_ast::parse_dbc;
+    use crate::ast::signal::SignalValue;
+
+    #[test]
+    fn test_signal_db_01() {
+        let network = parse_dbc(
+            r#"VERSION ""
+
+NS_:
+
+BS_:
+
+BU_: Engine
+
+BO_ 100 EngineData: 8 Engine
+ SG_ Temperature : 0|8@1- (0.5,-40) [0|0] "degC" Vector__XXX
+ SG_ Gear : 8|3@1+ (1,0) [0|0] "" Vector__XXX
+
+BO_ 200 Status: 4 Engine
+ SG_ Pressure : 0|32@1+ (1,0) [0|0] "bar" Vector__XXX
+
+VAL_ 100 Gear 2 "Drive" 1 "Reverse" 0 "Park" ;
+
+SIG_VALTYPE_ 200 Pressure : 1;
+"#,
+        )
+        .unwrap();
+        let db = network.signal_db();
+        assert_eq!(db.entries.len(), 3);
+
+        let data = [0xF6, 0x02, 0, 0, 0, 0, 0, 0];
+        let temperature = db.get(100, "Temperature").unwrap();
+        assert_eq!(temperature.message_name, "EngineData");
+        assert_eq!(
+            temperature.decode(&data),
+            Some(SignalValue::Physical(-45.0))
+        );
+        assert_eq!(temperature.label(&data), None);
+
+        let gear = db.get(100, "Gear").unwrap();
+        assert_eq!(
+            gear.decode(&data),
+            Some(SignalValue::Label("Drive".to_string()))
+        );
+        assert_eq!(gear.label(&data), Some("Drive"));
+        assert_eq!(gear.decode(&[0, 7]), Some(SignalValue::Physical(7.0)));
+        assert_eq!(gear.decode(&[]), None);
+
+        let pressure = db.get(200, "Pressure").unwrap();
+        assert_eq!(
+            pressure.decode(&2.5_f32.to_le_bytes()),
+            Some(SignalValue::Physical(2.5))
+        );
+        assert!(db.get(200, "Gear").is_none());
+    }
+}
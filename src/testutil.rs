@@ -0,0 +1,42 @@
+use crate::ast::network_ast::parse_dbc;
+
+/// Assert that `input` parses, and that formatting the network and parsing the result
+/// gives the same network again, so downstream crates can check their own fixtures
+/// against the round-trip guarantee of [`NetworkAst`](crate::ast::network_ast::NetworkAst)'s
+/// `Display`.
+///
+/// # Panics
+///
+/// If `input` or the formatted network does not parse, or the two networks differ.
+pub fn assert_roundtrip(input: &str) {
+    let network = parse_dbc(input).unwrap_or_else(|e| panic!("input does not parse: {e}"));
+    let formatted = network.to_string();
+    let reparsed = parse_dbc(&formatted)
+        .unwrap_or_else(|e| panic!("formatted network does not parse: {e}\n{formatted}"));
+    assert_eq!(reparsed, network, "network changed after a round-trip");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn test_assert_roundtrip_01() {
+        use std::fs;
+
+        use crate::encoding::decode_utf8_or_cp1252;
+
+        for entry in fs::read_dir("dbc/mytest").unwrap() {
+            let path = entry.unwrap().path();
+            let bytes = fs::read(&path).unwrap();
+            assert_roundtrip(&decode_utf8_or_cp1252(&bytes).unwrap());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "input does not parse")]
+    fn test_assert_roundtrip_02() {
+        assert_roundtrip("VERSION \"\"\n");
+    }
+}
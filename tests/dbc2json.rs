@@ -0,0 +1,30 @@
+#![cfg(feature = "bin")]
+
+use std::path::PathBuf;
+use std::process::Command;
+
+fn dbc2json(output: &PathBuf, extra_args: &[&str]) -> String {
+    let status = Command::new(env!("CARGO_BIN_EXE_dbc2json"))
+        .arg("dbc/mytest/abs.dbc")
+        .arg(output)
+        .args(extra_args)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let json = std::fs::read_to_string(output).unwrap();
+    std::fs::remove_file(output).unwrap();
+    json
+}
+
+#[test]
+fn test_dbc2json_compact_01() {
+    let dir = std::env::temp_dir();
+    let pretty = dbc2json(&dir.join("rrdbc_dbc2json_pretty.json"), &[]);
+    let compact = dbc2json(&dir.join("rrdbc_dbc2json_compact.json"), &["--compact"]);
+
+    assert!(!compact.contains('\n'));
+    assert!(compact.len() < pretty.len());
+    let pretty: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+    let compact: serde_json::Value = serde_json::from_str(&compact).unwrap();
+    assert_eq!(pretty, compact);
+}
@@ -0,0 +1,43 @@
+//! `parse_dbc` must reject malformed input with an error, never a panic.
+
+use std::fs;
+
+use proptest::prelude::*;
+use rrdbc::ast::network_ast::parse_dbc;
+
+const TEMPLATE: &str = include_str!("../dbc/mytest/DBC_template.dbc");
+
+/// Inputs that used to panic, kept so the fixes stay in place.
+#[test]
+fn test_panic_regressions() {
+    for entry in fs::read_dir("tests/panic-regressions").unwrap() {
+        let path = entry.unwrap().path();
+        let input = String::from_utf8_lossy(&fs::read(&path).unwrap()).into_owned();
+        assert!(parse_dbc(&input).is_err(), "{} parsed", path.display());
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_random_bytes_never_panic(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        let _ = parse_dbc(&String::from_utf8_lossy(&bytes));
+    }
+
+    #[test]
+    fn test_dbc_like_text_never_panic(input in r#"(VERSION|NS_|BS_|BU_|BO_|SG_|EV_|CM_|BA_|VAL_|DUMMY_NODE_VECTOR|[ :;|@+\-\(\)\[\],"\n0-9a-zA-Z_]){0,64}"#) {
+        let _ = parse_dbc(&input);
+    }
+
+    #[test]
+    fn test_mutated_template_never_panic(
+        at in 0..TEMPLATE.len(),
+        len in 0usize..16,
+        insert in r#"[ :;|@+\-\(\)\[\],"\n0-9A-F]{0,12}"#,
+    ) {
+        let mut input = TEMPLATE.to_string();
+        if input.is_char_boundary(at) && input.is_char_boundary((at + len).min(input.len())) {
+            input.replace_range(at..(at + len).min(input.len()), &insert);
+        }
+        let _ = parse_dbc(&input);
+    }
+}
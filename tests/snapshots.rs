@@ -5,7 +5,7 @@ use std::path::Path;
 
 use insta::{assert_debug_snapshot, assert_snapshot, assert_yaml_snapshot, with_settings};
 use rrdbc::ast::network_ast::parse_dbc;
-use rrdbc::encoding::decode_cp1252;
+use rrdbc::encoding::decode_utf8_or_cp1252;
 
 /// Test parsing all DBC files
 #[test]
@@ -63,7 +63,7 @@ fn parse_one_file(path: &Path) {
     eprintln!("Testing DBC file: {}", path.display());
     let file_name = path.file_stem().unwrap().to_string_lossy().to_string();
     let buffer = fs::read(path).unwrap();
-    if let Some(buffer) = decode_cp1252(&buffer) {
+    if let Some(buffer) = decode_utf8_or_cp1252(&buffer) {
         match parse_dbc(buffer.as_ref()) {
             Ok(dbc) => assert_yaml_snapshot!(file_name, dbc),
             Err(e) => {
@@ -72,7 +72,7 @@ fn parse_one_file(path: &Path) {
             }
         }
     } else {
-        let error = format!("Failed to decode {file_name}.dbc as cp1252");
+        let error = format!("Failed to decode {file_name}.dbc as utf-8 or cp1252");
         eprintln!("{error}");
         assert_snapshot!(format!("!error___{file_name}"), error);
     }